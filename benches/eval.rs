@@ -0,0 +1,79 @@
+// Compare repeated evaluation of a cubic curve at evenly spaced points with
+// the batch evaluation, the sampling iterator, and the forward differencing,
+// and the De Casteljau's algorithm with the nested multiplication for a high
+// degree polynomial.
+
+use bernstein::Bernstein;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use num::Complex;
+
+const NUMBER_OF_POINTS: usize = 2001;
+
+fn cubic() -> Bernstein<Complex<f64>, f64, 4> {
+    Bernstein::new([
+        Complex::new(0.0, 0.0),
+        Complex::new(2.5, 1.0),
+        Complex::new(-0.5, 1.0),
+        Complex::new(2.0, 0.0),
+    ])
+}
+
+fn bench_eval(c: &mut Criterion) {
+    let p = cubic();
+    let u: Vec<f64> = (0..NUMBER_OF_POINTS)
+        .map(|k| k as f64 / (NUMBER_OF_POINTS - 1) as f64)
+        .collect();
+    let mut out = vec![Complex::new(0.0, 0.0); NUMBER_OF_POINTS];
+
+    let mut group = c.benchmark_group("cubic");
+
+    group.bench_function("eval", |b| {
+        b.iter(|| {
+            for (x, y) in u.iter().zip(out.iter_mut()) {
+                *y = black_box(&p).eval(*x);
+            }
+        })
+    });
+
+    group.bench_function("eval_many", |b| {
+        b.iter(|| black_box(&p).eval_many(&u, &mut out))
+    });
+
+    group.bench_function("sample", |b| {
+        b.iter(|| {
+            for (y, v) in out.iter_mut().zip(black_box(&p).sample(NUMBER_OF_POINTS)) {
+                *y = v;
+            }
+        })
+    });
+
+    group.bench_function("forward_differences", |b| {
+        b.iter(|| {
+            for (y, v) in out
+                .iter_mut()
+                .zip(black_box(&p).forward_differences(NUMBER_OF_POINTS))
+            {
+                *y = v;
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_high_degree(c: &mut Criterion) {
+    let coef: [f64; 25] = std::array::from_fn(|i| ((i * 7) % 11) as f64 / 11.0 - 0.5);
+    let p: Bernstein<f64, f64, 25> = Bernstein::new(coef);
+
+    let mut group = c.benchmark_group("degree_24");
+
+    group.bench_function("eval", |b| b.iter(|| black_box(&p).eval(black_box(0.3))));
+    group.bench_function("eval_vs", |b| {
+        b.iter(|| black_box(&p).eval_vs(black_box(0.3)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_eval, bench_high_degree);
+criterion_main!(benches);
@@ -9,7 +9,7 @@ use plotters::prelude::*;
 
 const FILENAME: &str = "cubic_bezier.png";
 const PLOT_LABEL: &str = "Control polygon: (0.0), (-0.5, 1.0), (2.5, 1.0), (2.0, 0.0)";
-const NUMBER_OF_POINTS: i32 = 2000;
+const NUMBER_OF_POINTS: usize = 2000;
 
 // Specify control polygon of the Bezier curve as points in the complex plane.
 const CONTROL_POLYGON: [Complex<f32>; 4] = [
@@ -37,10 +37,9 @@ fn main()-> Result<(), Box<dyn std::error::Error>> {
     chart.configure_mesh().draw()?;
 
     chart
-        .draw_series(LineSeries::new((0..=NUMBER_OF_POINTS)
-        // Sample the curve at each point along the x-axis.
-        .map(|x| x as f32 / (NUMBER_OF_POINTS as f32))
-        .map(|x| (c.eval(x).re, c.eval(x).im)), &RED)).unwrap()
+        .draw_series(LineSeries::new(c.sample(NUMBER_OF_POINTS + 1)
+        // Sample the curve at evenly spaced points of the parameter.
+        .map(|z| (z.re, z.im)), &RED)).unwrap()
         .label(PLOT_LABEL)
         .legend(|(x,y)| PathElement::new(vec![(x,y), (x + 20,y)], &RED));
 
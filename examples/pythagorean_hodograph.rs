@@ -60,10 +60,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     chart.configure_mesh().draw()?;
 
     chart
-        .draw_series(LineSeries::new((0..=1000)
-        // Sample the curve at each point along the x-axis.
-        .map(|x| x as f32 / (1000 as f32))
-        .map(|x| (p.eval(x).re, p.eval(x).im)), (&RED).stroke_width(2)))?;
+        .draw_series(LineSeries::new(p.sample(1001)
+        // Sample the curve at evenly spaced points of the parameter.
+        .map(|z| (z.re, z.im)), (&RED).stroke_width(2)))?;
 
     // Draw supporting corner lines.
     chart
@@ -0,0 +1,239 @@
+//! Arc length of curves in the Bernstein basis and its inverse, which allow to
+//! sample curves at points evenly spaced along the curve.
+
+use num::{Float, FromPrimitive, Zero};
+use std::ops::{Add, Mul, Sub};
+
+use crate::{Bernstein, Dot};
+
+// Nodes and weights of the 5-point Gauss-Legendre quadrature over the
+// interval (-1, 1), which is exact for polynomials of degree up to 9.
+const NODES: [f64; 5] = [
+    -0.906_179_845_938_664,
+    -0.538_469_310_105_683_1,
+    0.0,
+    0.538_469_310_105_683_1,
+    0.906_179_845_938_664,
+];
+const WEIGHTS: [f64; 5] = [
+    0.236_926_885_056_189_1,
+    0.478_628_670_499_366_5,
+    0.568_888_888_888_888_9,
+    0.478_628_670_499_366_5,
+    0.236_926_885_056_189_1,
+];
+
+// Maximum depth of the adaptive subdivision of the quadrature.
+const MAX_DEPTH: usize = 48;
+
+// Maximum number of iterations of the Newton's method.
+const MAX_ITER: usize = 64;
+
+impl<T, U, const N: usize> Bernstein<T, U, N>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T> + Zero + Dot<U>,
+    U: Float + FromPrimitive,
+{
+    /// Calculate the arc length of the curve between the points `a` and `b`
+    /// of its interval, i.e. the integral of the speed `|p'(u)|` from `a` to
+    /// `b`, which is negative if `b < a`. The integral is calculated by the
+    /// adaptive Gauss-Legendre quadrature, which is exact up to the rounding
+    /// errors for Pythagorean-hodograph curves of degree up to 9.
+    ///
+    /// See R. T. Farouki, "Pythagorean-Hodograph Curves: Algebra and Geometry
+    /// Inseparable", Geometry and Computing (Eds. H. Edelsbrunner, K. Polthier,
+    /// and L Kobbelt) Springer (2008).
+    pub fn arc_length(&self, a: U, b: U) -> U {
+        if a == b {
+            return U::zero();
+        }
+
+        let whole = self.gauss_legendre(a, b);
+        // The rounding errors of the speed do not depend on its value, e.g.
+        // near the cusps, so that the tolerance is relative to the bound of
+        // the length rather than to the length itself.
+        let tol = tolerance::<U>() * self.max_speed() * (b - a).abs();
+        // Subdivision cannot converge, e.g. for NaN or infinite arguments.
+        if !tol.is_finite() {
+            return whole;
+        }
+        self.adaptive(a, b, whole, tol, MAX_DEPTH)
+    }
+
+    /// Return the point `u` of the interval of the curve where the arc length
+    /// from the start of the interval is `s`, which is found by the Newton's
+    /// method safeguarded by bisection. The length `s` is clamped to the
+    /// total length of the curve.
+    pub fn param_at_length(&self, s: U) -> U {
+        let (a, b) = self.segm;
+        let total = self.arc_length(a, b);
+        self.invert_length(a, U::zero(), s.max(U::zero()).min(total), total)
+            .0
+    }
+
+    /// Return an iterator over `n` points of the interval of the curve, which
+    /// split the curve into `n - 1` pieces of equal arc length, including
+    /// both end points.
+    pub fn params_by_length(&self, n: usize) -> ArcLengthParams<T, U, N> {
+        let (a, b) = self.segm;
+
+        ArcLengthParams {
+            poly: *self,
+            total: self.arc_length(a, b),
+            u: a,
+            l: U::zero(),
+            k: 0,
+            n,
+        }
+    }
+
+    /// Return an iterator over the values of the curve at `n` points evenly
+    /// spaced along the curve, including both end points.
+    pub fn sample_by_length(&self, n: usize) -> impl ExactSizeIterator<Item = T> {
+        let poly = *self;
+        self.params_by_length(n).map(move |u| poly.eval(u))
+    }
+
+    // Upper bound of the speed over the interval of the curve, which is the
+    // largest norm of the coefficients of the derivative.
+    fn max_speed(&self) -> U {
+        let factor = U::from_usize(N.saturating_sub(1)).unwrap() / (self.segm.1 - self.segm.0);
+
+        self.coef
+            .windows(2)
+            .map(|c| {
+                let d = c[1] - c[0];
+                d.dot(&d).sqrt()
+            })
+            .fold(U::zero(), U::max)
+            * factor.abs()
+    }
+
+    // Integral of the speed over the interval (a, b) by the 5-point
+    // Gauss-Legendre quadrature.
+    fn gauss_legendre(&self, a: U, b: U) -> U {
+        let two = U::from_usize(2).unwrap();
+        let mid = (a + b) / two;
+        let half = (b - a) / two;
+
+        NODES
+            .iter()
+            .zip(WEIGHTS.iter())
+            .fold(U::zero(), |sum, (x, w)| {
+                sum + U::from_f64(*w).unwrap() * self.speed(mid + half * U::from_f64(*x).unwrap())
+            })
+            * half
+    }
+
+    // Adaptive quadrature that splits the interval (a, b) in halves until the
+    // sum of the halves agrees with the `whole` to the tolerance `tol`.
+    fn adaptive(&self, a: U, b: U, whole: U, tol: U, depth: usize) -> U {
+        let mid = (a + b) / U::from_usize(2).unwrap();
+        let left = self.gauss_legendre(a, mid);
+        let right = self.gauss_legendre(mid, b);
+
+        if depth == 0 || (left + right - whole).abs() <= tol {
+            return left + right;
+        }
+
+        let tol = tol / U::from_usize(2).unwrap();
+        self.adaptive(a, mid, left, tol, depth - 1) + self.adaptive(mid, b, right, tol, depth - 1)
+    }
+
+    // Find the point with the arc length `s` from the start of the interval
+    // starting from the point `u` with the known arc length `l <= s`, and
+    // return the point with its arc length.
+    fn invert_length(&self, u: U, l: U, s: U, total: U) -> (U, U) {
+        let tol = tolerance::<U>() * total;
+        let (mut lo, mut hi) = (u, self.segm.1);
+        let (mut u, mut l) = (u, l);
+
+        for _ in 0..MAX_ITER {
+            let f = l - s;
+            if f.abs() <= tol {
+                break;
+            }
+
+            if f < U::zero() {
+                lo = u;
+            } else {
+                hi = u;
+            }
+
+            // Fall back to bisection if the Newton step leaves the bracket,
+            // e.g. where the speed vanishes.
+            let mut next = u - f / self.speed(u);
+            if !(next > lo && next < hi) {
+                next = (lo + hi) / U::from_usize(2).unwrap();
+            }
+
+            l = l + self.arc_length(u, next);
+            u = next;
+        }
+
+        (u, l)
+    }
+}
+
+// Relative tolerance of the arc length calculations.
+fn tolerance<U>() -> U
+where
+    U: Float + FromPrimitive,
+{
+    U::epsilon() * U::from_usize(64).unwrap()
+}
+
+/// Iterator over the points of the interval of a curve in the Bernstein basis
+/// that are evenly spaced along the curve, which is created by
+/// [`Bernstein::params_by_length`].
+#[derive(Debug, Clone)]
+pub struct ArcLengthParams<T, U, const N: usize> {
+    poly: Bernstein<T, U, N>,
+    total: U,
+    u: U,
+    l: U,
+    k: usize,
+    n: usize,
+}
+
+impl<T, U, const N: usize> Iterator for ArcLengthParams<T, U, N>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T> + Zero + Dot<U>,
+    U: Float + FromPrimitive,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        if self.k >= self.n {
+            return None;
+        }
+
+        // The first and the last points are exactly the ends of the interval,
+        // and the other points are found starting from the previous one.
+        let u = if self.k == 0 {
+            self.poly.segm.0
+        } else if self.k + 1 == self.n {
+            self.poly.segm.1
+        } else {
+            let s =
+                self.total * U::from_usize(self.k).unwrap() / U::from_usize(self.n - 1).unwrap();
+            (self.u, self.l) = self.poly.invert_length(self.u, self.l, s, self.total);
+            self.u
+        };
+        self.k += 1;
+
+        Some(u)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rest = self.n - self.k;
+        (rest, Some(rest))
+    }
+}
+
+impl<T, U, const N: usize> ExactSizeIterator for ArcLengthParams<T, U, N>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T> + Zero + Dot<U>,
+    U: Float + FromPrimitive,
+{
+}
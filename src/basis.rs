@@ -0,0 +1,271 @@
+//! Conversions between the Bernstein basis and other polynomial bases.
+
+use num::{FromPrimitive, Num, Zero};
+use std::ops::{Add, Mul, Sub};
+
+use crate::binomial::binomial_as;
+use crate::Bernstein;
+
+impl<T, U, const N: usize> Bernstein<T, U, N>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T> + Zero,
+    U: Copy + Num + FromPrimitive,
+{
+    /// Create new instance of a Bernstein polynomial over the default interval
+    /// (0, 1) from an array of coefficients `a` in the power (monomial) basis,
+    /// so that the polynomial is `a[0] + a[1] * u + ... + a[N - 1] * u^(N - 1)`.
+    ///
+    /// Note that the conversion between the monomial and the Bernstein bases
+    /// is ill-conditioned, and in the floating point arithmetic the loss of
+    /// accuracy grows exponentially with the degree of the polynomial.
+    ///
+    /// See R. T. Farouki, "The Bernstein polynomial basis: A centennial
+    /// retrospective", Computer Aided Geometric Design, 29(6) (2012).
+    #[allow(clippy::needless_range_loop)]
+    pub fn from_monomial(a: [T; N]) -> Bernstein<T, U, N> {
+        let n = N - 1;
+        let mut coef = [T::zero(); N];
+
+        for i in 0..=n {
+            for k in 0..=i {
+                coef[i] = coef[i] + a[k] * (binomial_as::<U>(i, k) / binomial_as::<U>(n, k));
+            }
+        }
+
+        Bernstein {
+            segm: (U::zero(), U::one()),
+            coef,
+        }
+    }
+
+    /// Return an array of coefficients `a` of the polynomial in the power
+    /// (monomial) basis of the parameter `u` over the interval of the
+    /// polynomial, so that it equals `a[0] + a[1] * u + ... + a[N - 1] * u^(N - 1)`.
+    ///
+    /// Note that the conversion between the monomial and the Bernstein bases
+    /// is ill-conditioned, and in the floating point arithmetic the loss of
+    /// accuracy grows exponentially with the degree of the polynomial, and
+    /// with the distance of the interval from the origin.
+    ///
+    /// See R. T. Farouki, "The Bernstein polynomial basis: A centennial
+    /// retrospective", Computer Aided Geometric Design, 29(6) (2012).
+    #[allow(clippy::needless_range_loop)]
+    pub fn to_monomial(&self) -> [T; N] {
+        let n = N - 1;
+
+        // Coefficients in the power basis of the normalized parameter
+        // `s = (u - a) / (b - a)`.
+        let mut c = [T::zero(); N];
+        for k in 0..=n {
+            for i in 0..=k {
+                let f = binomial_as::<U>(n, k) * binomial_as::<U>(k, i);
+                if (k - i).is_multiple_of(2) {
+                    c[k] = c[k] + self.coef[i] * f;
+                } else {
+                    c[k] = c[k] - self.coef[i] * f;
+                }
+            }
+        }
+
+        // Expand powers of `s` in powers of `u`.
+        let scale = U::one() / (self.segm.1 - self.segm.0);
+        let shift = U::zero() - self.segm.0;
+        let mut a = [T::zero(); N];
+        let mut scale_k = U::one();
+        for k in 0..=n {
+            let mut shift_kj = U::one();
+            for j in (0..=k).rev() {
+                a[j] = a[j] + c[k] * (scale_k * binomial_as::<U>(k, j) * shift_kj);
+                shift_kj = shift_kj * shift;
+            }
+            scale_k = scale_k * scale;
+        }
+
+        a
+    }
+
+    /// Create new instance of a Bernstein polynomial over the default interval
+    /// (0, 1) from an array of coefficients `c` in the basis of Chebyshev
+    /// polynomials of the first kind `T_k` that are mapped from (-1, 1) onto
+    /// the interval of the polynomial.
+    ///
+    /// See A. Rababah, "Transformation of Chebyshev–Bernstein polynomial
+    /// basis", Computational Methods in Applied Mathematics, 3(4) (2003).
+    pub fn from_chebyshev(c: [T; N]) -> Bernstein<T, U, N> {
+        Self::from_orthogonal(c, chebyshev::<U>)
+    }
+
+    /// Return an array of coefficients of the polynomial in the basis of
+    /// Chebyshev polynomials of the first kind `T_k` that are mapped from
+    /// (-1, 1) onto the interval of the polynomial.
+    ///
+    /// See A. Rababah, "Transformation of Chebyshev–Bernstein polynomial
+    /// basis", Computational Methods in Applied Mathematics, 3(4) (2003).
+    #[allow(clippy::needless_range_loop)]
+    pub fn to_chebyshev(&self) -> [T; N] {
+        let n = N - 1;
+        let mut c = [T::zero(); N];
+
+        // Projections onto the Chebyshev polynomials with the weight
+        // `1 / sqrt(s * (1 - s))`, where the integrals of the products of the
+        // Bernstein basis polynomials are calculated in the closed form.
+        let mut pow4 = U::one();
+        for _ in 0..n {
+            pow4 = pow4 * U::from_usize(4).unwrap();
+        }
+
+        for k in 0..=n {
+            let norm = if k == 0 {
+                U::one()
+            } else {
+                U::from_usize(2).unwrap()
+            };
+            for i in 0..=n {
+                let mut w = U::zero();
+                for j in 0..=k {
+                    let r = i + j;
+                    let f = binomial_as::<U>(2 * k, 2 * j)
+                        * binomial_as::<U>(n, i)
+                        * binomial_as::<U>(2 * r, r)
+                        * binomial_as::<U>(2 * (n + k - r), n + k - r)
+                        / binomial_as::<U>(n + k, r);
+                    if (k - j).is_multiple_of(2) {
+                        w = w + f;
+                    } else {
+                        w = w - f;
+                    }
+                }
+                c[k] = c[k] + self.coef[i] * (norm * w / pow4);
+            }
+            pow4 = pow4 * U::from_usize(4).unwrap();
+        }
+
+        c
+    }
+
+    /// Create new instance of a Bernstein polynomial over the default interval
+    /// (0, 1) from an array of coefficients `c` in the basis of Legendre
+    /// polynomials `P_k` that are mapped from (-1, 1) onto the interval of
+    /// the polynomial.
+    ///
+    /// See R. T. Farouki, "Legendre–Bernstein basis transformations", Journal
+    /// of Computational and Applied Mathematics, 119(1-2) (2000).
+    pub fn from_legendre(c: [T; N]) -> Bernstein<T, U, N> {
+        Self::from_orthogonal(c, legendre::<U>)
+    }
+
+    /// Return an array of coefficients of the polynomial in the basis of
+    /// Legendre polynomials `P_k` that are mapped from (-1, 1) onto the
+    /// interval of the polynomial.
+    ///
+    /// See R. T. Farouki, "Legendre–Bernstein basis transformations", Journal
+    /// of Computational and Applied Mathematics, 119(1-2) (2000).
+    #[allow(clippy::needless_range_loop)]
+    pub fn to_legendre(&self) -> [T; N] {
+        let n = N - 1;
+        let mut c = [T::zero(); N];
+
+        // Projections onto the Legendre polynomials, where the integrals of
+        // the products of the Bernstein basis polynomials are calculated in
+        // the closed form.
+        for k in 0..=n {
+            for i in 0..=n {
+                let mut w = U::zero();
+                for j in 0..=k {
+                    let f =
+                        binomial_as::<U>(k, j) * binomial_as::<U>(k, j) * binomial_as::<U>(n, i)
+                            / binomial_as::<U>(n + k, i + j);
+                    if (k + j).is_multiple_of(2) {
+                        w = w + f;
+                    } else {
+                        w = w - f;
+                    }
+                }
+                c[k] = c[k]
+                    + self.coef[i]
+                        * (w * U::from_usize(2 * k + 1).unwrap()
+                            / U::from_usize(n + k + 1).unwrap());
+            }
+        }
+
+        c
+    }
+
+    // Create new instance from coefficients in a polynomial basis, where
+    // `basis(k, i)` is the i-th coefficient of the k-th polynomial of the
+    // basis in the Bernstein basis of the degree `k`.
+    #[allow(clippy::needless_range_loop)]
+    fn from_orthogonal<F>(c: [T; N], basis: F) -> Bernstein<T, U, N>
+    where
+        F: Fn(usize, usize) -> U,
+    {
+        let n = N - 1;
+        let mut coef = [T::zero(); N];
+
+        for k in 0..=n {
+            // Elevate the k-th basis polynomial to the degree `n`.
+            for j in 0..=n {
+                let mut e = U::zero();
+                for i in j.saturating_sub(n - k)..=std::cmp::min(k, j) {
+                    e = e + basis(k, i) * binomial_as::<U>(k, i) * binomial_as::<U>(n - k, j - i)
+                        / binomial_as::<U>(n, j);
+                }
+                coef[j] = coef[j] + c[k] * e;
+            }
+        }
+
+        Bernstein {
+            segm: (U::zero(), U::one()),
+            coef,
+        }
+    }
+}
+
+// Coefficient `i` of the Chebyshev polynomial of the first kind of the degree
+// `k` over (0, 1) in the Bernstein basis of the same degree.
+fn chebyshev<U>(k: usize, i: usize) -> U
+where
+    U: Num + FromPrimitive,
+{
+    let t = binomial_as::<U>(2 * k, 2 * i) / binomial_as::<U>(k, i);
+    if (k - i).is_multiple_of(2) {
+        t
+    } else {
+        U::zero() - t
+    }
+}
+
+// Coefficient `i` of the Legendre polynomial of the degree `k` over (0, 1) in
+// the Bernstein basis of the same degree.
+fn legendre<U>(k: usize, i: usize) -> U
+where
+    U: Num + FromPrimitive,
+{
+    let t = binomial_as::<U>(k, i);
+    if (k + i).is_multiple_of(2) {
+        t
+    } else {
+        U::zero() - t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chebyshev_second_order() {
+        // 8 s^2 - 8 s + 1
+        assert_eq!(chebyshev::<i32>(2, 0), 1);
+        assert_eq!(chebyshev::<i32>(2, 1), -3);
+        assert_eq!(chebyshev::<i32>(2, 2), 1);
+    }
+
+    #[test]
+    fn legendre_second_order() {
+        // 6 s^2 - 6 s + 1
+        assert_eq!(legendre::<i32>(2, 0), 1);
+        assert_eq!(legendre::<i32>(2, 1), -2);
+        assert_eq!(legendre::<i32>(2, 2), 1);
+    }
+}
@@ -0,0 +1,227 @@
+//! Binomial coefficients for the operations in the Bernstein basis.
+
+use num::{FromPrimitive, Num};
+use std::array;
+
+/// Return the binomial coefficient `(n, k)` "n choose k", or `None` if it
+/// overflows `usize`. Intermediate results never exceed the coefficient.
+pub fn binomial(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+    let k = std::cmp::min(k, n - k);
+
+    // Each intermediate result is the binomial coefficient `(n - k + i, i)`,
+    // and the product is calculated in the wider type.
+    let mut c: usize = 1;
+    for i in 1..=k {
+        let next = c as u128 * (n - k + i) as u128 / i as u128;
+        c = usize::try_from(next).ok()?;
+    }
+
+    Some(c)
+}
+
+// Number of rows of the Pascal's triangle precomputed at compile time, whose
+// coefficients fit into `usize` also on 32-bit targets.
+const ROWS: usize = 34;
+
+const PASCAL: [[usize; ROWS]; ROWS] = pascal::<ROWS>();
+
+/// Return the table of binomial coefficients `(n, k)` for `n, k < N`, where
+/// the row `n` is the `n`-th row of the Pascal's triangle padded with zeros.
+/// It can be evaluated at compile time, e.g. in `const` items.
+///
+/// # Panics
+/// Panics, or fails to compile in `const` context, if the coefficients
+/// overflow `usize`.
+pub const fn pascal<const N: usize>() -> [[usize; N]; N] {
+    let mut table = [[0usize; N]; N];
+
+    let mut n = 0;
+    while n < N {
+        table[n][0] = 1;
+        let mut k = 1;
+        while k <= n {
+            table[n][k] = table[n - 1][k - 1]
+                .checked_add(table[n - 1][k])
+                .expect("binomial coefficient overflows usize");
+            k += 1;
+        }
+        n += 1;
+    }
+
+    table
+}
+
+/// Return the binomial coefficient `(n, k)` "n choose k" in the type `U`. It
+/// is taken from the table precomputed at compile time for `n < 34`, and is
+/// calculated directly in the type `U` by the multiplicative formula for
+/// large degrees, so that the floating point types are not limited by the
+/// overflow of `usize`. The result is exact for the exact types, and is
+/// rounded for the floating point types.
+///
+/// # Panics
+/// Panics if the coefficient from the table, or a factor of the formula,
+/// cannot be represented by `U`. For the integer types `U` the intermediate
+/// products of the formula overflow before the coefficient does, which panics
+/// in debug builds; see [`binomial`] for the checked calculation.
+pub fn binomial_as<U>(n: usize, k: usize) -> U
+where
+    U: Num + FromPrimitive,
+{
+    if k > n {
+        return U::zero();
+    }
+    if n < ROWS {
+        return from_table(n, k);
+    }
+    let k = std::cmp::min(k, n - k);
+
+    let mut c = U::one();
+    for i in 1..=k {
+        c = c * U::from_usize(n - k + i).unwrap() / U::from_usize(i).unwrap();
+    }
+
+    c
+}
+
+/// Return the row `N - 1` of the Pascal's triangle, i.e. the binomial
+/// coefficients `(N - 1, k)` for `k < N` in the type `U`, which are taken
+/// from the table precomputed at compile time for `N <= 34`, and are
+/// calculated by the recurrence from the previous coefficient in the row for
+/// large degrees.
+///
+/// # Panics
+/// Panics if the coefficients from the table cannot be represented by `U`.
+pub fn binomial_row<U, const N: usize>() -> [U; N]
+where
+    U: Copy + Num + FromPrimitive,
+{
+    let mut row = [U::one(); N];
+    fill_row(&mut row);
+    row
+}
+
+// Fill the slice of length `n + 1` with the binomial coefficients `(n, k)`.
+pub(crate) fn fill_row<U>(row: &mut [U])
+where
+    U: Copy + Num + FromPrimitive,
+{
+    if row.is_empty() {
+        return;
+    }
+
+    let n = row.len() - 1;
+    if n < ROWS {
+        for (k, c) in row.iter_mut().enumerate() {
+            *c = from_table(n, k);
+        }
+        return;
+    }
+
+    row[0] = U::one();
+    for k in 1..=n {
+        // Symmetric coefficients are copied to get the same rounding.
+        row[k] = if 2 * k > n {
+            row[n - k]
+        } else {
+            row[k - 1] * U::from_usize(n - k + 1).unwrap() / U::from_usize(k).unwrap()
+        };
+    }
+}
+
+// Binomial coefficient `(n, k)` for `n < ROWS` from the precomputed table.
+fn from_table<U: FromPrimitive>(n: usize, k: usize) -> U {
+    U::from_usize(PASCAL[n][k])
+        .expect("binomial coefficient cannot be represented by the parameter type")
+}
+
+// Binomial coefficients as an array of `U` of the row `n < K`, padded with
+// zeros to the length `K`.
+pub(crate) fn row_in<U, const K: usize>(n: usize) -> [U; K]
+where
+    U: Copy + Num + FromPrimitive,
+{
+    let mut row: [U; K] = array::from_fn(|_| U::zero());
+    fill_row(&mut row[..=n]);
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binomial_n_zero() {
+        assert_eq!(binomial(0, 0), Some(1));
+        assert_eq!(binomial(0, 1), Some(0));
+    }
+
+    #[test]
+    fn binomial_n_k() {
+        assert_eq!(binomial(7, 0), Some(1));
+        assert_eq!(binomial(7, 1), Some(7));
+        assert_eq!(binomial(7, 2), Some(21));
+        assert_eq!(binomial(7, 3), Some(35));
+        assert_eq!(binomial(7, 4), Some(35));
+        assert_eq!(binomial(7, 5), Some(21));
+        assert_eq!(binomial(7, 6), Some(7));
+        assert_eq!(binomial(7, 7), Some(1));
+        assert_eq!(binomial(7, 8), Some(0));
+
+        assert_eq!(binomial(6, 0), Some(1));
+        assert_eq!(binomial(6, 1), Some(6));
+        assert_eq!(binomial(6, 2), Some(15));
+        assert_eq!(binomial(6, 3), Some(20));
+        assert_eq!(binomial(6, 4), Some(15));
+        assert_eq!(binomial(6, 5), Some(6));
+        assert_eq!(binomial(6, 6), Some(1));
+        assert_eq!(binomial(6, 7), Some(0));
+    }
+
+    #[test]
+    fn binomial_large() {
+        assert_eq!(binomial(62, 31), Some(465428353255261088));
+        assert_eq!(binomial(1000, 998), Some(499500));
+    }
+
+    // Largest central binomial coefficients that fit into `usize`.
+    #[test]
+    fn binomial_overflow() {
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(binomial(67, 33), Some(14226520737620288370));
+            assert_eq!(binomial(68, 34), None);
+        }
+        #[cfg(target_pointer_width = "32")]
+        {
+            assert_eq!(binomial(34, 17), Some(2333606220));
+            assert_eq!(binomial(35, 17), None);
+        }
+        assert_eq!(binomial(200, 100), None);
+    }
+
+    #[test]
+    fn table_rows() {
+        for (n, row) in PASCAL.iter().enumerate() {
+            for (k, c) in row.iter().enumerate() {
+                assert_eq!(Some(*c), binomial(n, k));
+            }
+        }
+
+        let row: [f64; 40] = binomial_row();
+        assert_eq!(row[0], 1.0);
+        assert_eq!(row[20], binomial(39, 20).unwrap() as f64);
+    }
+
+    #[test]
+    fn fill_row_small() {
+        let mut row = [0.0; 6];
+        fill_row(&mut row);
+        assert_eq!(row, [1.0, 5.0, 10.0, 10.0, 5.0, 1.0]);
+
+        let row: [f64; 5] = row_in(2);
+        assert_eq!(row, [1.0, 2.0, 1.0, 0.0, 0.0]);
+    }
+}
@@ -0,0 +1,85 @@
+//! Bounding boxes of curves in the Bernstein basis.
+
+use num::{Float, FromPrimitive};
+use std::array;
+use std::ops::{Add, Mul, Sub};
+
+use crate::ops::{diff_coef, elevate_coef};
+use crate::{Bernstein, Components};
+
+impl<T, U, const N: usize> Bernstein<T, U, N>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T> + Components<U>,
+    U: Float + FromPrimitive,
+{
+    /// Return the lower and upper corners of the axis-aligned bounding box of
+    /// the control polygon, which contains the curve by the convex hull
+    /// property.
+    pub fn control_polygon_bounds(&self) -> (T, T) {
+        let mut lo = self.coef[0];
+        let mut hi = self.coef[0];
+
+        for c in &self.coef[1..] {
+            for i in 0..T::DIM {
+                let x = c.component(i);
+                if x < lo.component(i) {
+                    *lo.component_mut(i) = x;
+                }
+                if x > hi.component(i) {
+                    *hi.component_mut(i) = x;
+                }
+            }
+        }
+
+        (lo, hi)
+    }
+
+    /// Return the lower and upper corners of the tight axis-aligned bounding
+    /// box of the curve over its interval. The extremal values of each
+    /// component are found at the end points of the interval and at the roots
+    /// of the derivative of the component.
+    pub fn bounding_box(&self) -> (T, T) {
+        let start = self.eval(self.segm.0);
+        let end = self.eval(self.segm.1);
+        let mut lo = start;
+        let mut hi = start;
+
+        // Roots of the derivative are only needed to the square root of the
+        // machine precision, since the extremal values are stationary.
+        let tol = U::epsilon().sqrt() * (self.segm.1 - self.segm.0).abs();
+
+        for i in 0..T::DIM {
+            let comp: Bernstein<U, U, N> = Bernstein {
+                segm: self.segm,
+                coef: array::from_fn(|k| self.coef[k].component(i)),
+            };
+
+            let mut update = |x: U| {
+                if x < lo.component(i) {
+                    *lo.component_mut(i) = x;
+                }
+                if x > hi.component(i) {
+                    *hi.component_mut(i) = x;
+                }
+            };
+
+            update(end.component(i));
+            if N < 2 {
+                continue;
+            }
+
+            // Derivative of the component elevated to the size `N`, which
+            // has the same roots.
+            let hodo: Bernstein<U, U, N> = Bernstein {
+                segm: self.segm,
+                coef: elevate_coef(&diff_coef(&comp.coef, self.segm.1 - self.segm.0)),
+            };
+
+            for (u, _) in hodo.roots(tol) {
+                update(comp.eval(u));
+            }
+        }
+
+        (lo, hi)
+    }
+}
@@ -0,0 +1,202 @@
+//! B-spline curves and their conversion to piecewise polynomials in the
+//! Bernstein basis.
+
+use num::{FromPrimitive, Num};
+use std::array;
+use std::ops::{Add, Mul, Sub};
+
+use crate::{Bernstein, BezierSpline};
+
+/// Contains control points of type `T` and the non-decreasing knot vector of
+/// type `U` of a B-spline curve of the order `N`, i.e. the segments of the
+/// curve are polynomials of the degree `N - 1`. The curve is defined over the
+/// interval between the knots `N - 1` and `M`, where `M` is the number of the
+/// control points.
+#[derive(Debug, Clone)]
+pub struct BSpline<T, U, const N: usize> {
+    coef: Vec<T>,
+    knots: Vec<U>,
+}
+
+impl<T, U, const N: usize> BSpline<T, U, N>
+where
+    U: Copy + Num + PartialOrd,
+{
+    /// Create new instance of a B-spline curve from the control points and
+    /// the knot vector.
+    ///
+    /// # Panics
+    /// Panics if there are less than `N` control points, if the number of
+    /// knots is not the number of control points plus `N`, or if the knots
+    /// are decreasing.
+    pub fn new(coef: Vec<T>, knots: Vec<U>) -> BSpline<T, U, N> {
+        assert!(N > 0 && coef.len() >= N);
+        assert!(knots.len() == coef.len() + N);
+        assert!(
+            knots.windows(2).all(|t| t[0] <= t[1]),
+            "knots are decreasing"
+        );
+
+        BSpline { coef, knots }
+    }
+
+    /// Return the control points of the curve.
+    pub fn coef(&self) -> &[T] {
+        &self.coef
+    }
+
+    /// Return the knot vector of the curve.
+    pub fn knots(&self) -> &[U] {
+        &self.knots
+    }
+
+    /// Return the interval (a, b) over which the curve is defined.
+    pub fn interval(&self) -> (U, U) {
+        (self.knots[N - 1], self.knots[self.coef.len()])
+    }
+
+    // Index `j` of the non-empty knot span `knots[j] <= u < knots[j + 1]`
+    // within the interval of the curve, where `u` at the end of the interval
+    // belongs to the last non-empty span.
+    fn span(&self, u: U) -> usize {
+        let p = N - 1;
+        let m = self.coef.len();
+
+        let mut j = p + self.knots[p + 1..m].partition_point(|t| *t <= u);
+        while j > p && self.knots[j] == self.knots[j + 1] {
+            j -= 1;
+        }
+        j
+    }
+}
+
+impl<T, U, const N: usize> BSpline<T, U, N>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T>,
+    U: Copy + Num + FromPrimitive + PartialOrd,
+{
+    /// Create new instance of a B-spline curve from the piecewise polynomial
+    /// in the Bernstein basis that is `C^k` continuous at the joints. The knot
+    /// vector is clamped at the end points, and the break points between the
+    /// segments are the internal knots of the multiplicity `N - 1 - k`.
+    ///
+    /// The control points are calculated using blossoms of the segments, and
+    /// the conversion is exact only if the piecewise polynomial is indeed
+    /// `C^k` continuous.
+    ///
+    /// See H.-P. Seidel, "A new multiaffine approach to B-splines", Computer
+    /// Aided Geometric Design, 6(1) (1989).
+    ///
+    /// # Panics
+    /// Panics if `k >= N - 1`.
+    pub fn from_bezier(spline: &BezierSpline<T, U, N>, k: usize) -> BSpline<T, U, N> {
+        assert!(k + 1 < N);
+        let p = N - 1;
+        let segments = spline.segments();
+
+        let mut knots = Vec::with_capacity(2 * N + (segments.len() - 1) * (p - k));
+        knots.extend([segments[0].segm.0; N]);
+        for s in &segments[1..] {
+            knots.extend(std::iter::repeat_n(s.segm.0, p - k));
+        }
+        knots.extend([segments[segments.len() - 1].segm.1; N]);
+
+        let m = knots.len() - N;
+        let coef = (0..m)
+            .map(|i| {
+                // Any segment over the support of the B-spline basis function
+                // yields the same blossom value for a `C^k` continuous curve.
+                let j = (std::cmp::max(i, p)..m)
+                    .find(|&j| knots[j] < knots[j + 1])
+                    .unwrap();
+                let mid = (knots[j] + knots[j + 1]) / U::from_usize(2).unwrap();
+                spline.segment(mid).polar(|r| knots[i + 1 + r])
+            })
+            .collect();
+
+        BSpline { coef, knots }
+    }
+
+    /// Evaluate the curve at the point `u` within its interval using the
+    /// De Boor's algorithm.
+    ///
+    /// See Piegl & Tiller. "The NURBS book". Springer Science & Business Media
+    /// (2012) -- Sec. 3.2.
+    pub fn eval(&self, u: U) -> T {
+        self.polar(self.span(u), |_| u)
+    }
+
+    /// Insert the knot `u` within the interval of the curve using the Boehm's
+    /// algorithm, which adds a control point without changing the curve.
+    ///
+    /// See W. Boehm, "Inserting new knots into B-spline curves",
+    /// Computer-Aided Design, 12(4) (1980).
+    ///
+    /// # Panics
+    /// Panics if `u` is outside of the interval of the curve, or if the
+    /// multiplicity of the knot `u` would exceed the order `N` of the curve,
+    /// e.g. at the ends of a clamped knot vector.
+    pub fn insert_knot(&mut self, u: U) {
+        let (a, b) = self.interval();
+        assert!(a <= u && u <= b);
+        assert!(
+            self.knots.iter().filter(|t| **t == u).count() < N,
+            "multiplicity of the knot exceeds the order of the curve"
+        );
+
+        let p = N - 1;
+        let j = self.span(u);
+
+        let mut coef = Vec::with_capacity(self.coef.len() + 1);
+        coef.extend_from_slice(&self.coef[..=j - p]);
+        for i in j - p + 1..=j {
+            let alpha = (u - self.knots[i]) / (self.knots[i + p] - self.knots[i]);
+            coef.push(self.coef[i - 1] * (U::one() - alpha) + self.coef[i] * alpha);
+        }
+        coef.extend_from_slice(&self.coef[j..]);
+
+        self.coef = coef;
+        self.knots.insert(j + 1, u);
+    }
+
+    /// Convert the curve into a piecewise polynomial in the Bernstein basis,
+    /// where each non-empty knot span within the interval of the curve is a
+    /// separate segment.
+    pub fn to_bezier(&self) -> BezierSpline<T, U, N> {
+        let p = N - 1;
+
+        let segments = (p..self.coef.len())
+            .filter(|&j| self.knots[j] < self.knots[j + 1])
+            .map(|j| {
+                let (a, b) = (self.knots[j], self.knots[j + 1]);
+                let coef: [T; N] =
+                    array::from_fn(|i| self.polar(j, |r| if r < p - i { a } else { b }));
+                Bernstein::with_interval(coef, a, b)
+            })
+            .collect();
+
+        BezierSpline::new(segments)
+    }
+
+    // Evaluate the blossom of the polynomial over the knot span `j` at the
+    // arguments `arg(0), ..., arg(N - 2)` using the De Boor's algorithm with a
+    // different parameter value at each level.
+    fn polar<F>(&self, j: usize, arg: F) -> T
+    where
+        F: Fn(usize) -> U,
+    {
+        let p = N - 1;
+        let mut d: [T; N] = array::from_fn(|i| self.coef[j - p + i]);
+
+        for r in 1..=p {
+            let u = arg(r - 1);
+            for i in (r..=p).rev() {
+                let k = j - p + i;
+                let alpha = (u - self.knots[k]) / (self.knots[k + p + 1 - r] - self.knots[k]);
+                d[i] = d[i - 1] * (U::one() - alpha) + d[i] * alpha;
+            }
+        }
+
+        d[p]
+    }
+}
@@ -0,0 +1,113 @@
+//! Compensated evaluation of scalar polynomials in the Bernstein basis.
+
+use num::{Float, FromPrimitive};
+
+use crate::Bernstein;
+
+impl<U, const N: usize> Bernstein<U, U, N>
+where
+    U: Float + FromPrimitive,
+{
+    /// Evaluate polynomial at the point `u` within its interval by the
+    /// compensated De Casteljau's algorithm, and return the value together
+    /// with an a posteriori bound of its absolute error. The value is as
+    /// accurate as if it was computed by the De Casteljau's algorithm in the
+    /// doubled working precision, and then rounded to the working precision,
+    /// which preserves significant digits near the roots of the polynomial.
+    ///
+    /// Rounding errors of each operation are calculated exactly by the error
+    /// free transformations TwoSum and TwoProduct, and are accumulated into a
+    /// correction of the value. The normalized parameter `(u - a) / (b - a)`
+    /// is rounded once, so that the polynomial is evaluated exactly at the
+    /// parameter `u` only over the default interval (0, 1).
+    ///
+    /// See H. Jiang, S. Li, L. Cheng & F. Su, "Accurate evaluation of a
+    /// polynomial and its derivative in Bernstein form", Computers &
+    /// Mathematics with Applications, 60(3) (2010), and Ph. Langlois &
+    /// N. Louvet, "How to ensure a faithful polynomial evaluation with the
+    /// compensated Horner algorithm", 18th IEEE Symposium on Computer
+    /// Arithmetic (2007).
+    pub fn eval_compensated(&self, u: U) -> (U, U) {
+        let n = N - 1;
+        let t = (u - self.segm.0) / (self.segm.1 - self.segm.0);
+        let (r, rho) = two_sum(U::one(), -t);
+
+        let mut b = self.coef;
+        // Corrections of the coefficients, and the bounds of their magnitudes.
+        let mut db = [U::zero(); N];
+        let mut eb = [U::zero(); N];
+
+        for k in 1..=n {
+            for i in 0..=n - k {
+                let (s, pi_s) = two_product(r, b[i]);
+                let (v, pi_v) = two_product(t, b[i + 1]);
+                let (w, sigma) = two_sum(s, v);
+                let pi_r = rho * b[i];
+
+                db[i] = r * db[i] + t * db[i + 1] + (pi_s + pi_v + sigma + pi_r);
+                eb[i] = r.abs() * eb[i]
+                    + t.abs() * eb[i + 1]
+                    + (pi_s.abs() + pi_v.abs() + sigma.abs() + pi_r.abs());
+                b[i] = w;
+            }
+        }
+
+        // Rounding error of the value itself is calculated exactly, since it
+        // dominates the bound where the value is well conditioned.
+        let (value, delta) = two_sum(b[0], db[0]);
+
+        // Unit roundoff and the usual factor `gamma(k) = k u / (1 - k u)`.
+        let unit = U::epsilon() / U::from_usize(2).unwrap();
+        let gamma = |k: usize| {
+            let ku = U::from_usize(k).unwrap() * unit;
+            ku / (U::one() - ku)
+        };
+
+        // The terms `2 u^2 |value|` and `1 / (1 - 2 u)` cover the rounding
+        // errors in the calculation of the bound.
+        let two = U::from_usize(2).unwrap();
+        let bound = (delta.abs() + (gamma(3 * n + 2) * eb[0] + two * unit * unit * value.abs()))
+            / (U::one() - two * unit);
+
+        (value, bound)
+    }
+}
+
+// Error free transformation of the sum `a + b = x + y`, where `x` is the
+// floating point sum.
+//
+// See D. E. Knuth, "The Art of Computer Programming", Vol. 2, Addison-Wesley.
+fn two_sum<U: Float>(a: U, b: U) -> (U, U) {
+    let x = a + b;
+    let z = x - a;
+    let y = (a - (x - z)) + (b - z);
+    (x, y)
+}
+
+// Error free transformation of the product `a * b = x + y`, where `x` is the
+// floating point product, using the fused multiply-add.
+fn two_product<U: Float>(a: U, b: U) -> (U, U) {
+    let x = a * b;
+    let y = a.mul_add(b, -x);
+    (x, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_sum_exact() {
+        let (x, y) = two_sum(1.0, 1.0e-20);
+        assert_eq!(x, 1.0);
+        assert_eq!(y, 1.0e-20);
+    }
+
+    #[test]
+    fn two_product_exact() {
+        let a = 1.0 + f64::EPSILON;
+        let (x, y) = two_product(a, a);
+        assert_eq!(x, 1.0 + 2.0 * f64::EPSILON);
+        assert_eq!(y, f64::EPSILON * f64::EPSILON);
+    }
+}
@@ -0,0 +1,75 @@
+//! Composition of polynomials in the Bernstein basis.
+
+use num::{FromPrimitive, Num, Zero};
+use std::ops::{Add, Mul};
+
+use crate::binomial::binomial_row;
+use crate::{Bernstein, DynBernstein};
+
+impl<T, U, const N: usize> Bernstein<T, U, N>
+where
+    T: Copy + Add<Output = T> + Mul<U, Output = T> + Zero,
+    U: Copy + Num + FromPrimitive,
+{
+    /// Return new instance that is the composition `p(q(t))` of the original
+    /// polynomial `p` with the scalar polynomial `q` that maps its interval
+    /// into the interval of `p`. The composition is defined over the interval
+    /// of `q`, and is calculated by the products of polynomials in the
+    /// Bernstein basis as [`Bernstein::compose_into`].
+    ///
+    /// See R. T. Farouki, "Pythagorean-Hodograph Curves: Algebra and Geometry
+    /// Inseparable", Geometry and Computing (Eds. H. Edelsbrunner, K. Polthier,
+    /// and L Kobbelt) Springer (2008). -- p. 258, Sec. 11.7, Eq. (11.20).
+    #[cfg(feature = "nightly")]
+    pub fn compose<const M: usize>(
+        &self,
+        q: &Bernstein<U, U, M>,
+    ) -> Bernstein<T, U, { (N - 1) * (M - 1) + 1 }> {
+        self.compose_into(q)
+    }
+
+    /// Return new instance that is the composition `p(q(t))` of the original
+    /// polynomial `p` with the scalar polynomial `q` of the explicitly given
+    /// size `K = (N - 1) * (M - 1) + 1`.
+    ///
+    /// The composition is a sum of products of the powers of the normalized
+    /// parameter `s = (q(t) - a) / (b - a)` and of `1 - s`, which are
+    /// calculated directly in the Bernstein basis over the interval of `q`.
+    ///
+    /// # Panics
+    /// Panics if `K != (N - 1) * (M - 1) + 1`.
+    #[allow(clippy::needless_range_loop)]
+    pub fn compose_into<const M: usize, const K: usize>(
+        &self,
+        q: &Bernstein<U, U, M>,
+    ) -> Bernstein<T, U, K> {
+        assert!(N > 0 && M > 0);
+        assert!(K == (N - 1) * (M - 1) + 1);
+
+        let (a, b) = self.segm;
+        let (c, d) = q.segm;
+
+        let one = DynBernstein::with_interval(vec![U::one()], c, d);
+        let s = DynBernstein::with_interval(q.coef.map(|x| (x - a) / (b - a)).to_vec(), c, d);
+        let t = DynBernstein::with_interval(q.coef.map(|x| (b - x) / (b - a)).to_vec(), c, d);
+
+        // Powers `s^i` and `(1 - s)^i` for `i = 0, ..., N - 1`.
+        let mut spow = vec![one.clone()];
+        let mut tpow = vec![one];
+        for i in 1..N {
+            spow.push(spow[i - 1].clone() * s.clone());
+            tpow.push(tpow[i - 1].clone() * t.clone());
+        }
+
+        let row: [U; N] = binomial_row();
+        let mut coef = [T::zero(); K];
+        for i in 0..N {
+            let basis = spow[i].clone() * tpow[N - 1 - i].clone();
+            for k in 0..K {
+                coef[k] = coef[k] + self.coef[i] * (row[i] * basis.coef()[k]);
+            }
+        }
+
+        Bernstein { segm: q.segm, coef }
+    }
+}
@@ -0,0 +1,179 @@
+//! Polynomials in the Bernstein basis with the size known at runtime.
+
+use num::{FromPrimitive, Num, Zero};
+use std::ops::{Add, Mul, Sub};
+
+use crate::ops::{diff_coef, product_slice};
+use crate::Bernstein;
+
+/// Contains coefficients of a polynomial of type `T` in the Bernstein basis
+/// over an interval `(a, b)` of the parameter of type `U`, which is (0, 1) by
+/// default. Unlike [`Bernstein`], the number of dimensions in the basis is
+/// the length of the vector of coefficients that is only known at runtime.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynBernstein<T, U> {
+    coef: Vec<T>,
+    segm: (U, U),
+}
+
+impl<T, U> DynBernstein<T, U>
+where
+    U: Num,
+{
+    /// Create new instance of a Bernstein polynomial from a vector of
+    /// coefficients in the Bernstein basis over the default interval (0, 1).
+    pub fn new(coef: Vec<T>) -> DynBernstein<T, U> {
+        DynBernstein {
+            segm: (U::zero(), U::one()),
+            coef,
+        }
+    }
+
+    /// Create new instance of a Bernstein polynomial from a vector of
+    /// coefficients in the Bernstein basis over the interval (a, b).
+    ///
+    /// # Panics
+    /// Panics if the interval has zero length, i.e. `a == b`.
+    pub fn with_interval(coef: Vec<T>, a: U, b: U) -> DynBernstein<T, U> {
+        assert!(a != b, "interval has zero length");
+        DynBernstein { segm: (a, b), coef }
+    }
+
+    /// Return a slice of coefficients for a polynomial in the Bernstein basis.
+    pub fn coef(&self) -> &[T] {
+        &self.coef
+    }
+
+    /// Return the interval (a, b) over which the polynomial is defined.
+    pub fn interval(&self) -> (U, U)
+    where
+        U: Copy,
+    {
+        self.segm
+    }
+}
+
+impl<T, U> DynBernstein<T, U>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T>,
+    U: Copy + Num + FromPrimitive,
+{
+    /// Evaluate polynomial in the Bernstein basis at the point `u` within its
+    /// interval `a <= u <= b` using the De Casteljau's algorithm.
+    ///
+    /// See Piegl & Tiller. "The NURBS book". Springer Science & Business Media
+    /// (2012) -- p.24, A1.5.
+    ///
+    /// # Panics
+    /// Panics if there are no coefficients.
+    pub fn eval(&self, u: U) -> T {
+        let n = self.coef.len();
+        let mut q = self.coef.clone();
+
+        for k in 1..n {
+            for i in 0..n - k {
+                q[i] = q[i] * ((self.segm.1 - u) / (self.segm.1 - self.segm.0))
+                    + q[i + 1] * ((u - self.segm.0) / (self.segm.1 - self.segm.0));
+            }
+        }
+        q[0]
+    }
+
+    /// Return new instance that is a derivative of the original polynomial in
+    /// the Bernstein basis, which has one coefficient less.
+    ///
+    /// See Piegl & Tiller. "The NURBS book". Springer Science & Business Media
+    /// (2012) -- p.22, Eq. (1.9).
+    pub fn diff(&self) -> DynBernstein<T, U> {
+        DynBernstein {
+            segm: self.segm,
+            coef: diff_coef(&self.coef, self.segm.1 - self.segm.0),
+        }
+    }
+
+    /// Return new instance that is an integral of the original polynomial in
+    /// the Bernstein basis, which has one coefficient more.
+    ///
+    /// # Arguments
+    /// * `c` - the constant of integration.
+    ///
+    /// See R. T. Farouki, "Pythagorean-Hodograph Curves: Algebra and Geometry
+    /// Inseparable", Geometry and Computing (Eds. H. Edelsbrunner, K. Polthier,
+    /// and L Kobbelt) Springer (2008). -- p. 253, Sec. 11.2.6.
+    pub fn integ(&self, c: T) -> DynBernstein<T, U> {
+        // Integral of the empty (zero) polynomial is the constant.
+        if self.coef.is_empty() {
+            return DynBernstein {
+                segm: self.segm,
+                coef: vec![c],
+            };
+        }
+
+        let factor = (self.segm.1 - self.segm.0) / U::from_usize(self.coef.len()).unwrap();
+        let mut coef = Vec::with_capacity(self.coef.len() + 1);
+
+        coef.push(c);
+        for k in 0..self.coef.len() {
+            coef.push(coef[k] + self.coef[k] * factor);
+        }
+
+        DynBernstein {
+            segm: self.segm,
+            coef,
+        }
+    }
+}
+
+/// Calculate a product of two polynomials in the Bernstein basis.
+///
+/// See R. T. Farouki, "Pythagorean-Hodograph Curves: Algebra and Geometry
+/// Inseparable", Geometry and Computing (Eds. H. Edelsbrunner, K. Polthier,
+/// and L Kobbelt) Springer (2008). -- p. 258, Sec. 11.7, Eq. (11.20).
+///
+/// # Panics
+/// Panics if the polynomials are defined over different intervals.
+impl<T, U> Mul<DynBernstein<T, U>> for DynBernstein<T, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Mul<U, Output = T> + Zero,
+    U: Copy + Num + FromPrimitive,
+{
+    type Output = DynBernstein<T, U>;
+
+    fn mul(self, rhs: DynBernstein<T, U>) -> Self::Output {
+        assert!(
+            self.segm == rhs.segm,
+            "polynomials are defined over different intervals"
+        );
+
+        let coef = product_slice::<U, _, _, _, _>(&self.coef, &rhs.coef, |a, b| a * b);
+
+        DynBernstein {
+            segm: self.segm,
+            coef,
+        }
+    }
+}
+
+/// Convert a polynomial of a compile time size into the one of runtime size.
+impl<T, U, const N: usize> From<Bernstein<T, U, N>> for DynBernstein<T, U> {
+    fn from(p: Bernstein<T, U, N>) -> DynBernstein<T, U> {
+        DynBernstein {
+            coef: Vec::from(p.coef),
+            segm: p.segm,
+        }
+    }
+}
+
+/// Convert a polynomial of a runtime size into the one of compile time size
+/// `N`, which fails and returns the original polynomial back if the number of
+/// its coefficients is not `N`.
+impl<T, U, const N: usize> TryFrom<DynBernstein<T, U>> for Bernstein<T, U, N> {
+    type Error = DynBernstein<T, U>;
+
+    fn try_from(p: DynBernstein<T, U>) -> Result<Bernstein<T, U, N>, DynBernstein<T, U>> {
+        match <[T; N]>::try_from(p.coef) {
+            Ok(coef) => Ok(Bernstein { coef, segm: p.segm }),
+            Err(coef) => Err(DynBernstein { coef, segm: p.segm }),
+        }
+    }
+}
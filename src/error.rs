@@ -0,0 +1,33 @@
+//! Errors of the fallible operations on polynomials in the Bernstein basis.
+
+use std::fmt;
+
+/// Errors that are reported by the fallible operations instead of panics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// Number, such as the degree or a binomial coefficient, cannot be
+    /// represented by the type of the parameter.
+    Conversion,
+    /// Binomial coefficient overflows `usize`, and cannot be represented by
+    /// the type of the parameter approximately either.
+    Overflow,
+    /// Interval of the polynomial has zero length.
+    DegenerateInterval,
+    /// Polynomials are defined over different intervals.
+    IntervalMismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Conversion => write!(f, "number cannot be represented by the parameter type"),
+            Error::Overflow => write!(f, "binomial coefficient overflows usize"),
+            Error::DegenerateInterval => write!(f, "interval has zero length"),
+            Error::IntervalMismatch => {
+                write!(f, "polynomials are defined over different intervals")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
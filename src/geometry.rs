@@ -0,0 +1,155 @@
+//! Differential geometry of curves in the Bernstein basis: Frenet frames,
+//! curvature and torsion of planar curves in the complex plane, and of
+//! spatial curves in 3D.
+
+use num::complex::Complex;
+use num::{Float, FromPrimitive, Zero};
+use std::ops::{Add, Mul, Sub};
+
+use crate::ops::{diff_coef, product_slice};
+use crate::{Bernstein, Cross, Dot};
+
+impl<T, U, const N: usize> Bernstein<T, U, N>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T> + Zero + Dot<U>,
+    U: Float + FromPrimitive,
+{
+    /// Return the speed `|p'(u)|` of the curve at the point `u`.
+    pub fn speed(&self, u: U) -> U {
+        let [_, d1] = self.eval_derivs_into::<2>(u);
+        d1.dot(&d1).sqrt()
+    }
+
+    /// Return the unit tangent vector `p'(u) / |p'(u)|` of the curve at the
+    /// point `u`, which is not defined (NaN) where the derivative vanishes.
+    pub fn tangent(&self, u: U) -> T {
+        let [_, d1] = self.eval_derivs_into::<2>(u);
+        d1 * (U::one() / d1.dot(&d1).sqrt())
+    }
+
+    /// Return the numerator `p'(u) x p''(u)` of the curvature in the
+    /// Bernstein basis, so that the curvature is `|p' x p''| / |p'|^3`, which
+    /// is a scalar polynomial in the complex plane, and a vector polynomial
+    /// in 3D.
+    ///
+    /// See R. T. Farouki, "Pythagorean-Hodograph Curves: Algebra and Geometry
+    /// Inseparable", Geometry and Computing (Eds. H. Edelsbrunner, K. Polthier,
+    /// and L Kobbelt) Springer (2008).
+    ///
+    /// # Panics
+    /// Panics if `N < 3`.
+    #[cfg(feature = "nightly")]
+    pub fn curvature_numerator(&self) -> Bernstein<<T as Cross>::Output, U, { 2 * N - 4 }>
+    where
+        T: Cross,
+        <T as Cross>::Output: Copy
+            + Add<Output = <T as Cross>::Output>
+            + Mul<U, Output = <T as Cross>::Output>
+            + Zero,
+    {
+        self.curvature_numerator_into()
+    }
+
+    /// Return the numerator `p'(u) x p''(u)` of the curvature in the
+    /// Bernstein basis of the explicitly given size `K = 2 * N - 4`.
+    ///
+    /// # Panics
+    /// Panics if `N < 3`, or if `K != 2 * N - 4`.
+    pub fn curvature_numerator_into<const K: usize>(&self) -> Bernstein<<T as Cross>::Output, U, K>
+    where
+        T: Cross,
+        <T as Cross>::Output: Copy
+            + Add<Output = <T as Cross>::Output>
+            + Mul<U, Output = <T as Cross>::Output>
+            + Zero,
+    {
+        assert!(N >= 3);
+        assert!(K == 2 * N - 4);
+
+        let h = self.segm.1 - self.segm.0;
+        let d1 = diff_coef(&self.coef, h);
+        let d2 = diff_coef(&d1, h);
+
+        let coef = product_slice::<U, _, _, _, _>(&d1, &d2, |a, b| a.cross(&b));
+
+        Bernstein {
+            segm: self.segm,
+            coef: coef
+                .try_into()
+                .unwrap_or_else(|_| panic!("size of the product is not 2 * N - 4")),
+        }
+    }
+}
+
+impl<U, const N: usize> Bernstein<Complex<U>, U, N>
+where
+    U: Float + FromPrimitive,
+{
+    /// Return the unit normal vector of the planar curve at the point `u`,
+    /// which is the unit tangent rotated counterclockwise by the right angle.
+    pub fn normal(&self, u: U) -> Complex<U> {
+        self.tangent(u) * Complex::i()
+    }
+
+    /// Return the signed curvature `(p' x p'') / |p'|^3` of the planar curve
+    /// at the point `u`, which is positive where the curve turns
+    /// counterclockwise.
+    ///
+    /// See G. Farin, "Curves and Surfaces for CAGD: A Practical Guide",
+    /// Morgan Kaufmann (2002).
+    pub fn curvature(&self, u: U) -> U {
+        let [_, d1, d2] = self.eval_derivs_into::<3>(u);
+        let speed = d1.dot(&d1).sqrt();
+        d1.cross(&d2) / (speed * speed * speed)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<U, const N: usize> Bernstein<nalgebra::Vector3<U>, U, N>
+where
+    U: Float
+        + FromPrimitive
+        + nalgebra::Scalar
+        + nalgebra::ClosedAdd
+        + nalgebra::ClosedSub
+        + nalgebra::ClosedMul,
+{
+    /// Return the unit principal normal vector `b x t` of the curve at the
+    /// point `u`, which is not defined (NaN) where the curvature vanishes.
+    pub fn normal(&self, u: U) -> nalgebra::Vector3<U> {
+        self.binormal(u).cross(&self.tangent(u))
+    }
+
+    /// Return the unit binormal vector `(p' x p'') / |p' x p''|` of the curve
+    /// at the point `u`, which is not defined (NaN) where the curvature
+    /// vanishes.
+    pub fn binormal(&self, u: U) -> nalgebra::Vector3<U> {
+        let [_, d1, d2] = self.eval_derivs_into::<3>(u);
+        let b = d1.cross(&d2);
+        b * (U::one() / b.dot(&b).sqrt())
+    }
+
+    /// Return the curvature `|p' x p''| / |p'|^3` of the curve at the point
+    /// `u`.
+    ///
+    /// See G. Farin, "Curves and Surfaces for CAGD: A Practical Guide",
+    /// Morgan Kaufmann (2002).
+    pub fn curvature(&self, u: U) -> U {
+        let [_, d1, d2] = self.eval_derivs_into::<3>(u);
+        let b = d1.cross(&d2);
+        let speed = d1.dot(&d1).sqrt();
+        b.dot(&b).sqrt() / (speed * speed * speed)
+    }
+
+    /// Return the torsion `(p' x p'') . p''' / |p' x p''|^2` of the curve at
+    /// the point `u`, which is not defined (NaN) where the curvature
+    /// vanishes.
+    ///
+    /// See G. Farin, "Curves and Surfaces for CAGD: A Practical Guide",
+    /// Morgan Kaufmann (2002).
+    pub fn torsion(&self, u: U) -> U {
+        let [_, d1, d2, d3] = self.eval_derivs_into::<4>(u);
+        let b = d1.cross(&d2);
+        b.dot(&d3) / b.dot(&b)
+    }
+}
@@ -0,0 +1,74 @@
+//! Linear time evaluation of polynomials in the Bernstein basis by the nested
+//! multiplication.
+
+use num::{FromPrimitive, Num};
+use std::ops::{Add, Mul, Sub};
+
+use crate::Bernstein;
+
+/// Algorithms for the evaluation of polynomials in the Bernstein basis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Evaluator {
+    /// De Casteljau's algorithm of `O(N^2)` operations, which only takes
+    /// convex combinations of the coefficients within the interval, and is
+    /// numerically stable.
+    #[default]
+    DeCasteljau,
+    /// Volk-Schumaker nested multiplication of `O(N)` operations, which is a
+    /// Horner's scheme with binomial factors. It is exact in the exact
+    /// arithmetic, but the rounding errors in the floating point arithmetic
+    /// grow with the binomial coefficients, so that it is less accurate than
+    /// the De Casteljau's algorithm for high degrees.
+    VolkSchumaker,
+}
+
+impl<T, U, const N: usize> Bernstein<T, U, N>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T>,
+    U: Copy + Num + FromPrimitive + PartialOrd,
+{
+    /// Evaluate polynomial in the Bernstein basis at the point `u` within its
+    /// interval `a <= u <= b` using the `evaluator` algorithm.
+    pub fn eval_with(&self, u: U, evaluator: Evaluator) -> T {
+        match evaluator {
+            Evaluator::DeCasteljau => self.eval(u),
+            Evaluator::VolkSchumaker => self.eval_vs(u),
+        }
+    }
+
+    /// Evaluate polynomial in the Bernstein basis at the point `u` within its
+    /// interval `a <= u <= b` in `O(N)` operations by the nested
+    /// multiplication in the ratio `s / (1 - s)` or its inverse, whichever is
+    /// not greater than one, where `s = (u - a) / (b - a)`.
+    ///
+    /// See L. L. Schumaker & W. Volk, "Efficient evaluation of multivariate
+    /// polynomials", Computer Aided Geometric Design, 3(2) (1986).
+    pub fn eval_vs(&self, u: U) -> T {
+        let n = N - 1;
+        let h = self.segm.1 - self.segm.0;
+        let s = (u - self.segm.0) / h;
+        let t = (self.segm.1 - u) / h;
+
+        let reverse = s > t;
+
+        // Nested multiplication of `sum c[i] * binom(n, i) * r^i` starting
+        // from the highest power, with `r = s / t` or `r = t / s`.
+        let (r, scale) = if reverse { (t / s, s) } else { (s / t, t) };
+        let coef = |i: usize| {
+            if reverse {
+                self.coef[n - i]
+            } else {
+                self.coef[i]
+            }
+        };
+
+        let mut binom = U::one();
+        let mut acc = coef(n);
+        for i in (0..n).rev() {
+            binom = binom * U::from_usize(i + 1).unwrap() / U::from_usize(n - i).unwrap();
+            acc = acc * r + coef(i) * binom;
+        }
+
+        acc * num::pow(scale, n)
+    }
+}
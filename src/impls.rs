@@ -2,18 +2,20 @@
 
 use num::FromPrimitive;
 use num::Num;
+use num::Zero;
 use std::array;
 use std::ops::{Add, Mul, Sub};
 
-use crate::Bernstein;
+use crate::ops::{checked_product_coef, diff_coef, elevate_coef};
+use crate::{Bernstein, Error};
 
 impl<T, U, const N: usize> Bernstein<T, U, N>
 where
     T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T>,
     U: Copy + Num + FromPrimitive,
 {
-    /// Evaluate polynomial in the Bernstein basis at the point `0 <= u <= 1`
-    /// using the De Casteljau's algorithm.
+    /// Evaluate polynomial in the Bernstein basis at the point `u` within its
+    /// interval `a <= u <= b` using the De Casteljau's algorithm.
     ///
     /// See Piegl & Tiller. "The NURBS book". Springer Science & Business Media
     /// (2012) -- p.24, A1.5.
@@ -29,24 +31,215 @@ where
         q[0]
     }
 
+    /// Evaluate the polynomial and its first `K` derivatives at the point `u`
+    /// from a single De Casteljau triangle, and return them in the order of
+    /// increasing derivatives. Derivatives of orders higher than the degree
+    /// of the polynomial are zero.
+    ///
+    /// See G. Farin, "Curves and Surfaces for CAGD: A Practical Guide",
+    /// Morgan Kaufmann (2002).
+    #[cfg(feature = "nightly")]
+    pub fn eval_derivs<const K: usize>(&self, u: U) -> [T; K + 1]
+    where
+        T: Zero,
+    {
+        self.eval_derivs_into(u)
+    }
+
+    /// Evaluate the polynomial and its first `L - 1` derivatives at the point
+    /// `u`, where the explicitly given number of values `L` does not depend
+    /// on the generic constant expressions.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`.
+    pub fn eval_derivs_into<const L: usize>(&self, u: U) -> [T; L]
+    where
+        T: Zero,
+    {
+        assert!(N > 0);
+
+        let h = self.segm.1 - self.segm.0;
+        let mut derivs = [T::zero(); L];
+        let mut q = self.coef;
+
+        // At the level `N - 1 - j` of the triangle, the `j`-th derivative is
+        // the `j`-th forward difference of the remaining `j + 1` points.
+        for j in (0..N).rev() {
+            if j < L {
+                let mut d = q;
+                let mut factor = U::one();
+                for r in 1..=j {
+                    for i in 0..=j - r {
+                        d[i] = d[i + 1] - d[i];
+                    }
+                    factor = factor * U::from_usize(N - r).unwrap() / h;
+                }
+                derivs[j] = d[0] * factor;
+            }
+
+            if j > 0 {
+                for i in 0..j {
+                    q[i] = q[i] * ((self.segm.1 - u) / h) + q[i + 1] * ((u - self.segm.0) / h);
+                }
+            }
+        }
+
+        derivs
+    }
+
+    /// Evaluate the blossom (polar form) of the polynomial, which is the
+    /// unique symmetric multi-affine function of `N - 1` arguments that is
+    /// equal to the polynomial on the diagonal, i.e. when all the arguments
+    /// are the same.
+    ///
+    /// See L. Ramshaw, "Blossoms are polar forms", Computer Aided Geometric
+    /// Design, 6(4) (1989).
+    #[cfg(feature = "nightly")]
+    pub fn blossom(&self, args: &[U; N - 1]) -> T {
+        self.polar(|k| args[k])
+    }
+
+    /// Evaluate the blossom (polar form) of the polynomial at the slice of
+    /// `N - 1` arguments, which does not depend on the generic constant
+    /// expressions.
+    ///
+    /// # Panics
+    /// Panics if the number of arguments is not `N - 1`.
+    pub fn blossom_slice(&self, args: &[U]) -> T {
+        assert!(args.len() + 1 == N);
+        self.polar(|k| args[k])
+    }
+
+    // Evaluate the blossom (polar form) of the polynomial at the arguments
+    // `arg(0), ..., arg(N - 2)` using the De Casteljau's algorithm with a
+    // different parameter value at each level.
+    pub(crate) fn polar<F>(&self, arg: F) -> T
+    where
+        F: Fn(usize) -> U,
+    {
+        let mut q = self.coef;
+
+        for k in 1..=N - 1 {
+            let u = arg(k - 1);
+            for i in 0..=N - k - 1 {
+                q[i] = q[i] * ((self.segm.1 - u) / (self.segm.1 - self.segm.0))
+                    + q[i + 1] * ((u - self.segm.0) / (self.segm.1 - self.segm.0));
+            }
+        }
+        q[0]
+    }
+
+    /// Split the polynomial at the point `u` into two polynomials of the same
+    /// size that are defined over the intervals to the left and to the right
+    /// of `u`, respectively, using the De Casteljau's algorithm.
+    ///
+    /// See Piegl & Tiller. "The NURBS book". Springer Science & Business Media
+    /// (2012) -- p.24, A1.5.
+    ///
+    /// # Panics
+    /// Panics if `u` is an end of the interval, so that one of the pieces
+    /// would have zero length.
+    pub fn split(&self, u: U) -> (Bernstein<T, U, N>, Bernstein<T, U, N>) {
+        assert!(
+            u != self.segm.0 && u != self.segm.1,
+            "interval has zero length"
+        );
+
+        let mut q = self.coef;
+        let mut left = self.coef;
+        let mut right = self.coef;
+
+        for k in 1..=N - 1 {
+            for i in 0..=N - k - 1 {
+                q[i] = q[i] * ((self.segm.1 - u) / (self.segm.1 - self.segm.0))
+                    + q[i + 1] * ((u - self.segm.0) / (self.segm.1 - self.segm.0));
+            }
+            left[k] = q[0];
+            right[N - k - 1] = q[N - k - 1];
+        }
+
+        (
+            Bernstein {
+                segm: (self.segm.0, u),
+                coef: left,
+            },
+            Bernstein {
+                segm: (u, self.segm.1),
+                coef: right,
+            },
+        )
+    }
+
+    /// Return new instance that is the same polynomial in the Bernstein basis
+    /// over the interval `(a, b)`, which is usually a sub-interval of the
+    /// original one. The values at each point `u` are kept, so that the curve
+    /// is cut to the piece between `p(a)` and `p(b)`.
+    ///
+    /// See R. Goldman, "Pyramid Algorithms: A Dynamic Programming Approach to
+    /// Curves and Surfaces for Geometric Modeling", Morgan Kaufmann (2002).
+    ///
+    /// # Panics
+    /// Panics if the interval has zero length, i.e. `a == b`.
+    pub fn restrict(&self, a: U, b: U) -> Bernstein<T, U, N> {
+        assert!(a != b, "interval has zero length");
+
+        let coef: [T; N] =
+            array::from_fn(|i| -> T { self.polar(|k| if k < N - 1 - i { a } else { b }) });
+
+        Bernstein { segm: (a, b), coef }
+    }
+
+    /// Return new instance with the same coefficients in the Bernstein basis
+    /// over the new interval `(a, b)`. This does not change the curve, but
+    /// maps its parameterization linearly onto the new interval.
+    ///
+    /// # Panics
+    /// Panics if the interval has zero length, i.e. `a == b`.
+    pub fn rescale(&self, a: U, b: U) -> Bernstein<T, U, N> {
+        assert!(a != b, "interval has zero length");
+
+        Bernstein {
+            segm: (a, b),
+            coef: self.coef,
+        }
+    }
+
+    /// Return new instance that is the same curve over the new parameter
+    /// interval `(a, b)`, i.e. `q(a + (b - a) s) = p(a0 + (b0 - a0) s)` for
+    /// the original interval `(a0, b0)`. Unlike [`Bernstein::restrict`], which
+    /// keeps the values at each parameter and cuts the curve, the whole
+    /// curve is kept and only its parameter is mapped linearly, so that the
+    /// coefficients are the same as with [`Bernstein::rescale`].
+    ///
+    /// # Panics
+    /// Panics if the interval has zero length, i.e. `a == b`.
+    pub fn reparametrize(&self, a: U, b: U) -> Bernstein<T, U, N> {
+        self.rescale(a, b)
+    }
+
     /// Return new instance that is a derivative of the original polynomial in
     /// the Bernstein basis.
     ///
     /// See Piegl & Tiller. "The NURBS book". Springer Science & Business Media
     /// (2012) -- p.22, Eq. (1.9).
+    #[cfg(feature = "nightly")]
     pub fn diff(&self) -> Bernstein<T, U, { N - 1 }>
     where
         [(); N - 1]:,
     {
-        let coef: [T; N - 1] = array::from_fn(|i| -> T {
-            (self.coef[i + 1] - self.coef[i])
-                * (U::from_usize(N - 1).unwrap() / (self.segm.1 - self.segm.0))
-        });
+        self.diff_into()
+    }
 
-        Bernstein {
-            segm: self.segm,
-            coef,
-        }
+    /// Return new instance that is a derivative of the original polynomial in
+    /// the Bernstein basis of the explicitly given size `M = N - 1`, which
+    /// does not depend on the generic constant expressions.
+    ///
+    /// # Panics
+    /// Panics if `M + 1 != N`, if the interval has zero length, or if the
+    /// degree cannot be represented by the parameter type.
+    pub fn diff_into<const M: usize>(&self) -> Bernstein<T, U, M> {
+        self.try_diff_into()
+            .expect("cannot differentiate the polynomial")
     }
 
     /// Return new instance that is an integral of the original polynomial in
@@ -58,12 +251,86 @@ where
     /// See R. T. Farouki, "Pythagorean-Hodograph Curves: Algebra and Geometry
     /// Inseparable", Geometry and Computing (Eds. H. Edelsbrunner, K. Polthier,
     /// and L Kobbelt) Springer (2008). -- p. 253, Sec. 11.2.6.
+    #[cfg(feature = "nightly")]
     pub fn integ(&self, c: T) -> Bernstein<T, U, { N + 1 }>
     where
         [(); N + 1]:,
     {
-        let factor = (self.segm.1 - self.segm.0) / U::from_usize(N).unwrap();
-        let mut coef: [T; N + 1] = [c; N + 1];
+        self.integ_into(c)
+    }
+
+    /// Return new instance that is an integral of the original polynomial in
+    /// the Bernstein basis of the explicitly given size `M = N + 1`, which
+    /// does not depend on the generic constant expressions.
+    ///
+    /// # Arguments
+    /// * `c` - the constant of integration.
+    ///
+    /// # Panics
+    /// Panics if `M != N + 1`, or if the size cannot be represented by the
+    /// parameter type.
+    pub fn integ_into<const M: usize>(&self, c: T) -> Bernstein<T, U, M> {
+        self.try_integ_into(c)
+            .expect("cannot integrate the polynomial")
+    }
+
+    /// Return new instance that is a derivative of the original polynomial
+    /// as [`Bernstein::diff`], or an error if the interval has zero length,
+    /// or if the degree cannot be represented by the parameter type.
+    #[cfg(feature = "nightly")]
+    pub fn try_diff(&self) -> Result<Bernstein<T, U, { N - 1 }>, Error>
+    where
+        [(); N - 1]:,
+    {
+        self.try_diff_into()
+    }
+
+    /// Return new instance that is a derivative of the original polynomial of
+    /// the explicitly given size `M = N - 1` as [`Bernstein::diff_into`], or
+    /// an error if the interval has zero length, or if the degree cannot be
+    /// represented by the parameter type.
+    ///
+    /// # Panics
+    /// Panics if `M + 1 != N`.
+    pub fn try_diff_into<const M: usize>(&self) -> Result<Bernstein<T, U, M>, Error> {
+        assert!(M + 1 == N);
+
+        let h = self.segm.1 - self.segm.0;
+        if h.is_zero() {
+            return Err(Error::DegenerateInterval);
+        }
+        U::from_usize(N - 1).ok_or(Error::Conversion)?;
+
+        let diff = diff_coef(&self.coef, h);
+        Ok(Bernstein {
+            segm: self.segm,
+            coef: array::from_fn(|i| diff[i]),
+        })
+    }
+
+    /// Return new instance that is an integral of the original polynomial as
+    /// [`Bernstein::integ`], or an error if the size cannot be represented by
+    /// the parameter type.
+    #[cfg(feature = "nightly")]
+    pub fn try_integ(&self, c: T) -> Result<Bernstein<T, U, { N + 1 }>, Error>
+    where
+        [(); N + 1]:,
+    {
+        self.try_integ_into(c)
+    }
+
+    /// Return new instance that is an integral of the original polynomial of
+    /// the explicitly given size `M = N + 1` as [`Bernstein::integ_into`], or
+    /// an error if the size cannot be represented by the parameter type.
+    ///
+    /// # Panics
+    /// Panics if `M != N + 1`.
+    #[allow(clippy::needless_range_loop)]
+    pub fn try_integ_into<const M: usize>(&self, c: T) -> Result<Bernstein<T, U, M>, Error> {
+        assert!(M == N + 1);
+
+        let factor = (self.segm.1 - self.segm.0) / U::from_usize(N).ok_or(Error::Conversion)?;
+        let mut coef: [T; M] = [c; M];
 
         for k in 1..N + 1 {
             for j in 0..k {
@@ -71,9 +338,169 @@ where
             }
         }
 
-        Bernstein {
+        Ok(Bernstein {
             segm: self.segm,
             coef,
+        })
+    }
+
+    /// Return new instance that represents the same polynomial in the
+    /// Bernstein basis of the size `N + 1` (degree elevation).
+    ///
+    /// See R. T. Farouki, "The Bernstein polynomial basis: A centennial
+    /// retrospective", Computer Aided Geometric Design, 29(6) (2012) -- Sec. 5.1.
+    #[cfg(feature = "nightly")]
+    pub fn elevate(&self) -> Bernstein<T, U, { N + 1 }>
+    where
+        T: Zero,
+        [(); N + 1]:,
+    {
+        self.elevate_to()
+    }
+
+    /// Return new instance that represents the same polynomial in the
+    /// Bernstein basis of an arbitrary size `M >= N`.
+    ///
+    /// # Panics
+    /// Panics if `M < N`.
+    pub fn elevate_to<const M: usize>(&self) -> Bernstein<T, U, M>
+    where
+        T: Zero,
+    {
+        Bernstein {
+            segm: self.segm,
+            coef: elevate_coef(&self.coef),
         }
     }
+
+    /// Calculate a sum of two polynomials in the Bernstein basis of the
+    /// explicitly given size `K = max(N, M)`, which does not depend on the
+    /// generic constant expressions. The smaller polynomial is elevated to
+    /// the size of the larger one.
+    ///
+    /// # Panics
+    /// Panics if `K != max(N, M)`, or if the polynomials are defined over
+    /// different intervals.
+    pub fn add_into<const M: usize, const K: usize>(
+        &self,
+        rhs: &Bernstein<T, U, M>,
+    ) -> Bernstein<T, U, K>
+    where
+        T: Zero,
+    {
+        assert!(K == std::cmp::max(N, M));
+        assert!(
+            self.segm == rhs.segm,
+            "polynomials are defined over different intervals"
+        );
+
+        let lhs: [T; K] = elevate_coef(&self.coef);
+        let rhs: [T; K] = elevate_coef(&rhs.coef);
+
+        Bernstein {
+            segm: self.segm,
+            coef: array::from_fn(|i| lhs[i] + rhs[i]),
+        }
+    }
+
+    /// Calculate a difference of two polynomials in the Bernstein basis of the
+    /// explicitly given size `K = max(N, M)`, which does not depend on the
+    /// generic constant expressions. The smaller polynomial is elevated to
+    /// the size of the larger one.
+    ///
+    /// # Panics
+    /// Panics if `K != max(N, M)`, or if the polynomials are defined over
+    /// different intervals.
+    pub fn sub_into<const M: usize, const K: usize>(
+        &self,
+        rhs: &Bernstein<T, U, M>,
+    ) -> Bernstein<T, U, K>
+    where
+        T: Zero,
+    {
+        assert!(K == std::cmp::max(N, M));
+        assert!(
+            self.segm == rhs.segm,
+            "polynomials are defined over different intervals"
+        );
+
+        let lhs: [T; K] = elevate_coef(&self.coef);
+        let rhs: [T; K] = elevate_coef(&rhs.coef);
+
+        Bernstein {
+            segm: self.segm,
+            coef: array::from_fn(|i| lhs[i] - rhs[i]),
+        }
+    }
+}
+
+impl<T, U, const N: usize> Bernstein<T, U, N>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Mul<U, Output = T> + Zero,
+    U: Copy + Num + FromPrimitive,
+{
+    /// Calculate a product of two polynomials in the Bernstein basis of the
+    /// explicitly given size `K = N + M - 1`, which does not depend on the
+    /// generic constant expressions.
+    ///
+    /// See R. T. Farouki, "Pythagorean-Hodograph Curves: Algebra and Geometry
+    /// Inseparable", Geometry and Computing (Eds. H. Edelsbrunner, K. Polthier,
+    /// and L Kobbelt) Springer (2008). -- p. 258, Sec. 11.7, Eq. (11.20).
+    ///
+    /// # Panics
+    /// Panics if `K + 1 != N + M`, if the polynomials are defined over
+    /// different intervals, or if the binomial coefficients cannot be
+    /// represented by the parameter type.
+    pub fn mul_into<const M: usize, const K: usize>(
+        &self,
+        rhs: &Bernstein<T, U, M>,
+    ) -> Bernstein<T, U, K> {
+        assert!(
+            self.segm == rhs.segm,
+            "polynomials are defined over different intervals"
+        );
+
+        self.checked_mul_into(rhs)
+            .expect("binomial coefficients cannot be represented by the parameter type")
+    }
+
+    /// Calculate a product of two polynomials in the Bernstein basis as the
+    /// operator `*`, or return an error if the polynomials are defined over
+    /// different intervals, or if the binomial coefficients cannot be
+    /// represented by the parameter type.
+    #[cfg(feature = "nightly")]
+    pub fn checked_mul<const M: usize>(
+        &self,
+        rhs: &Bernstein<T, U, M>,
+    ) -> Result<Bernstein<T, U, { N + M - 1 }>, Error>
+    where
+        [(); N + M - 1]:,
+    {
+        self.checked_mul_into(rhs)
+    }
+
+    /// Calculate a product of two polynomials in the Bernstein basis of the
+    /// explicitly given size `K = N + M - 1` as [`Bernstein::mul_into`], or
+    /// return an error if the polynomials are defined over different
+    /// intervals, or if the binomial coefficients cannot be represented by
+    /// the parameter type. Coefficients that overflow `usize` are represented
+    /// approximately, e.g. by the floating point types.
+    ///
+    /// # Panics
+    /// Panics if `K + 1 != N + M`.
+    pub fn checked_mul_into<const M: usize, const K: usize>(
+        &self,
+        rhs: &Bernstein<T, U, M>,
+    ) -> Result<Bernstein<T, U, K>, Error> {
+        if self.segm != rhs.segm {
+            return Err(Error::IntervalMismatch);
+        }
+
+        Ok(Bernstein {
+            segm: self.segm,
+            coef: checked_product_coef::<U, _, _, _, _, N, M, K>(&self.coef, &rhs.coef, |a, b| {
+                a * b
+            })?,
+        })
+    }
 }
@@ -1,20 +1,57 @@
 //! Basic operations with generic polynomials in the Bernstein basis.
 //!
-//! This crate is experimental and depend on the unstable feature
-//! `generic_const_exprs` that is only available in the nightly build.
+//! This crate is experimental. With the default feature `nightly`, sizes of
+//! derivatives, integrals, sums and products are inferred from the sizes of
+//! the operands, which depends on the unstable feature `generic_const_exprs`
+//! that is only available in the nightly build. Without this feature, the
+//! crate builds on stable Rust, and the sizes of the results are given
+//! explicitly, e.g. by [`Bernstein::diff_into`], [`Bernstein::integ_into`],
+//! [`Bernstein::add_into`] and [`Bernstein::mul_into`], which are also
+//! available with the `nightly` feature.
 
-#![allow(incomplete_features)]
-#![feature(generic_const_exprs)]
-#![feature(step_trait)]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
 
 use num::Num;
 
+mod arclength;
+mod basis;
+mod binomial;
+mod bounds;
+mod bspline;
+mod compensated;
+mod compose;
+mod dynamic;
+mod error;
+mod geometry;
+mod horner;
 mod impls;
+mod linalg;
 mod ops;
+mod rational;
+mod reduce;
+mod roots;
+mod sample;
+mod spline;
+mod traits;
+mod vector;
+
+pub use arclength::ArcLengthParams;
+pub use binomial::{binomial, binomial_as, binomial_row, pascal};
+pub use bspline::BSpline;
+pub use dynamic::DynBernstein;
+pub use error::Error;
+pub use horner::Evaluator;
+pub use rational::RationalBernstein;
+pub use reduce::Constraint;
+pub use sample::{ForwardDifferences, Sample};
+pub use spline::{BezierSpline, Continuity};
+pub use traits::{Components, Cross, Dot, Norm};
 
 /// Contains coefficients of a polynomial of type `T` in the Bernstein basis
-/// over the default interval (0, 1). The number of dimensions in the basis
-/// is `N` which implies that the order of the basis polynomials in `N - 1`.
+/// over an interval `(a, b)` of the parameter of type `U`, which is (0, 1) by
+/// default. The number of dimensions in the basis is `N` which implies that
+/// the order of the basis polynomials in `N - 1`.
 #[derive(Debug, Clone, Copy)]
 pub struct Bernstein<T, U, const N: usize> {
     coef: [T; N],
@@ -34,10 +71,28 @@ where
         }
     }
 
+    /// Create new instance of a Bernstein polynomial from an array of
+    /// coefficients in the Bernstein basis over the interval (a, b).
+    ///
+    /// # Panics
+    /// Panics if the interval has zero length, i.e. `a == b`.
+    pub fn with_interval(coef: [T; N], a: U, b: U) -> Bernstein<T, U, N> {
+        assert!(a != b, "interval has zero length");
+        Bernstein { segm: (a, b), coef }
+    }
+
     /// Return an array of coefficients for a polynomial in the Bernstein basis.
     pub fn coef(&self) -> &[T; N] {
         &self.coef
     }
+
+    /// Return the interval (a, b) over which the polynomial is defined.
+    pub fn interval(&self) -> (U, U)
+    where
+        U: Copy,
+    {
+        self.segm
+    }
 }
 
 #[cfg(test)]
@@ -58,7 +113,12 @@ mod tests {
     fn bernstein_public_getset_api() {
         let coef_in = [1, 2, 3];
         let c: Bernstein<i32, i32, 3> = Bernstein::new(coef_in);
-        let coef_out = c.coef().clone();
+        let coef_out = *c.coef();
         assert_eq!(coef_in, coef_out);
+        assert_eq!(c.interval(), (0, 1));
+
+        let c: Bernstein<i32, i32, 3> = Bernstein::with_interval(coef_in, -1, 2);
+        assert_eq!(*c.coef(), coef_in);
+        assert_eq!(c.interval(), (-1, 2));
     }
 }
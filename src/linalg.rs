@@ -0,0 +1,51 @@
+//! Dense linear algebra routines for small systems with scalar matrices and
+//! generic right hand sides.
+
+use num::{Num, Zero};
+use std::ops::{Mul, Sub};
+
+// Solve linear system `a * x = b` by the Gaussian elimination without
+// pivoting, where `a` is a scalar matrix and `b` is a vector of coefficients.
+// Should only be called on matrices with non-vanishing leading principal
+// minors, e.g. symmetric positive definite ones.
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn solve<T, U, const M: usize>(mut a: [[U; M]; M], mut b: [T; M]) -> [T; M]
+where
+    T: Copy + Sub<Output = T> + Mul<U, Output = T> + Zero,
+    U: Copy + Num,
+{
+    for c in 0..M {
+        for r in c + 1..M {
+            let f = a[r][c] / a[c][c];
+            for j in c..M {
+                a[r][j] = a[r][j] - f * a[c][j];
+            }
+            b[r] = b[r] - b[c] * f;
+        }
+    }
+
+    let mut x = [T::zero(); M];
+    for r in (0..M).rev() {
+        let mut s = b[r];
+        for j in r + 1..M {
+            s = s - x[j] * a[r][j];
+        }
+        x[r] = s * (U::one() / a[r][r]);
+    }
+
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_spd() {
+        let a = [[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]];
+        let x = solve(a, [5.0, 5.0, 3.0]);
+        for v in x {
+            assert!((v - 1.0_f64).abs() < 1.0e-15);
+        }
+    }
+}
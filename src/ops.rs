@@ -1,45 +1,300 @@
 //! Implementations of binary operations on polynomials in the Bernstein basis.
 
 use num::{FromPrimitive, Num, Zero};
-use std::iter::Step;
-use std::ops::{Add, Mul, Sub};
+use std::array;
+use std::ops::{Add, Mul, Neg, Sub};
+#[cfg(feature = "nightly")]
+use std::ops::{AddAssign, SubAssign};
 
-use crate::Bernstein;
+use crate::binomial::{binomial, binomial_as, binomial_row, row_in};
+use crate::{Bernstein, Error};
 
-// Calculate binomial coefficient (n, k) "n choose k".
-// Should only be called on types that can be safely compared with `==`.
-fn binom<T>(n: T, k: T) -> T
+// Lower summation bound that does not overflow for unsized types.
+pub(crate) fn low_bound<T>(a: T, b: T) -> T
 where
-    T: Copy + Num + Step,
+    T: Num + PartialOrd,
 {
-    assert!(k >= T::zero());
-    assert!(n >= T::zero());
+    if a >= b {
+        return a - b;
+    }
+    T::zero()
+}
 
-    if k > n {
-        return T::zero();
+// Elevate coefficients of a polynomial in the Bernstein basis of size
+// `coef.len()` to the basis of size `K >= coef.len()`.
+//
+// See R. T. Farouki, "The Bernstein polynomial basis: A centennial
+// retrospective", Computer Aided Geometric Design, 29(6) (2012) -- Sec. 5.1.
+pub(crate) fn elevate_coef<T, U, const K: usize>(coef: &[T]) -> [T; K]
+where
+    T: Copy + Add<Output = T> + Mul<U, Output = T> + Zero,
+    U: Copy + Num + FromPrimitive,
+{
+    assert!(K >= coef.len());
+    if K == coef.len() {
+        return array::from_fn(|i| coef[i]);
     }
-    if k == T::zero() {
-        return T::one();
+
+    let mut elev = [T::zero(); K];
+
+    let n = coef.len() - 1;
+    let r = K - 1;
+
+    let row_n: [U; K] = row_in(n);
+    let row_rn: [U; K] = row_in(r - n);
+    let row_r: [U; K] = binomial_row();
+
+    for k in 0..=r {
+        for j in low_bound(k, r - n)..=std::cmp::min(n, k) {
+            elev[k] = elev[k] + coef[j] * (row_n[j] * row_rn[k - j] / row_r[k]);
+        }
     }
 
-    let mut next = n - k + T::one();
+    elev
+}
+
+// Coefficients of a derivative of a polynomial in the Bernstein basis over an
+// interval of the length `h`, which has one coefficient fewer.
+pub(crate) fn diff_coef<T, U>(coef: &[T], h: U) -> Vec<T>
+where
+    T: Copy + Sub<Output = T> + Mul<U, Output = T>,
+    U: Copy + Num + FromPrimitive,
+{
+    let factor = U::from_usize(coef.len().saturating_sub(1)).unwrap() / h;
+    coef.windows(2).map(|c| (c[1] - c[0]) * factor).collect()
+}
+
+// Calculate coefficients of a product of two polynomials in the Bernstein
+// basis of sizes `N` and `M`, where the product of coefficients is `f`. The
+// size of the product is `K = N + M - 1`. Panics if the binomial coefficients
+// cannot be represented by the parameter type `U`.
+//
+// See R. T. Farouki, "Pythagorean-Hodograph Curves: Algebra and Geometry
+// Inseparable", Geometry and Computing (Eds. H. Edelsbrunner, K. Polthier,
+// and L Kobbelt) Springer (2008). -- p. 258, Sec. 11.7, Eq. (11.20).
+pub(crate) fn product_coef<U, A, B, C, F, const N: usize, const M: usize, const K: usize>(
+    lhs: &[A; N],
+    rhs: &[B; M],
+    f: F,
+) -> [C; K]
+where
+    A: Copy,
+    B: Copy,
+    C: Copy + Add<Output = C> + Mul<U, Output = C> + Zero,
+    U: Copy + Num + FromPrimitive,
+    F: Fn(A, B) -> C,
+{
+    checked_product_coef(lhs, rhs, f)
+        .expect("binomial coefficients cannot be represented by the parameter type")
+}
+
+// Calculate coefficients of a product of two polynomials as `product_coef`,
+// or return an error if the binomial coefficients cannot be represented by
+// the parameter type `U`. Coefficients that overflow `usize` are only
+// represented approximately, e.g. by the floating point types.
+pub(crate) fn checked_product_coef<U, A, B, C, F, const N: usize, const M: usize, const K: usize>(
+    lhs: &[A; N],
+    rhs: &[B; M],
+    f: F,
+) -> Result<[C; K], Error>
+where
+    A: Copy,
+    B: Copy,
+    C: Copy + Add<Output = C> + Mul<U, Output = C> + Zero,
+    U: Copy + Num + FromPrimitive,
+    F: Fn(A, B) -> C,
+{
+    assert!(K + 1 == N + M);
+
+    let mut coef = [C::zero(); K];
 
-    for i in T::one()..k {
-        next = next * (n - k + T::one() + i) / (i + T::one());
+    let n = M - 1;
+    let m = N - 1;
+
+    let factor = |n: usize, k: usize| -> Result<U, Error> {
+        match binomial(n, k) {
+            Some(c) => U::from_usize(c).ok_or(Error::Conversion),
+            None => U::from_f64(binomial_as(n, k)).ok_or(Error::Overflow),
+        }
+    };
+
+    // Each row of binomial coefficients is only calculated once.
+    let row_m = (0..=m)
+        .map(|j| factor(m, j))
+        .collect::<Result<Vec<U>, _>>()?;
+    let row_n = (0..=n)
+        .map(|j| factor(n, j))
+        .collect::<Result<Vec<U>, _>>()?;
+    let row_mn = (0..=m + n)
+        .map(|k| factor(m + n, k))
+        .collect::<Result<Vec<U>, _>>()?;
+
+    for k in 0..=m + n {
+        for j in low_bound(k, n)..=std::cmp::min(m, k) {
+            coef[k] = coef[k] + f(lhs[j], rhs[k - j]) * (row_m[j] * row_n[k - j] / row_mn[k]);
+        }
     }
 
-    next
+    Ok(coef)
 }
 
-// Lower summation bound that does not overflow for unsized types.
-fn low_bound<T>(a: T, b: T) -> T
+// Calculate coefficients of a product of two polynomials as `product_coef`,
+// where the sizes of the polynomials are only known at runtime. The product
+// is empty if any of the polynomials has no coefficients.
+pub(crate) fn product_slice<U, A, B, C, F>(lhs: &[A], rhs: &[B], f: F) -> Vec<C>
 where
-    T: Num + PartialOrd,
+    A: Copy,
+    B: Copy,
+    C: Copy + Add<Output = C> + Mul<U, Output = C> + Zero,
+    U: Copy + Num + FromPrimitive,
+    F: Fn(A, B) -> C,
 {
-    if a >= b {
-        return a - b;
+    if lhs.is_empty() || rhs.is_empty() {
+        return Vec::new();
+    }
+
+    let m = lhs.len() - 1;
+    let n = rhs.len() - 1;
+    let mut coef = vec![C::zero(); m + n + 1];
+
+    let row_m: Vec<U> = (0..=m).map(|j| binomial_as(m, j)).collect();
+    let row_n: Vec<U> = (0..=n).map(|j| binomial_as(n, j)).collect();
+    let row_mn: Vec<U> = (0..=m + n).map(|k| binomial_as(m + n, k)).collect();
+
+    for k in 0..=m + n {
+        for j in low_bound(k, n)..=std::cmp::min(m, k) {
+            coef[k] = coef[k] + f(lhs[j], rhs[k - j]) * (row_m[j] * row_n[k - j] / row_mn[k]);
+        }
+    }
+
+    coef
+}
+
+/// Calculate a sum of two polynomials in the Bernstein basis. If the sizes of
+/// the polynomials are different, both are elevated to the larger size.
+///
+/// # Panics
+/// Panics if the polynomials are defined over different intervals.
+#[cfg(feature = "nightly")]
+impl<T, U, const N: usize, const M: usize> Add<Bernstein<T, U, { M }>> for Bernstein<T, U, { N }>
+where
+    T: Copy + Add<Output = T> + Mul<U, Output = T> + Zero,
+    U: Copy + Num + FromPrimitive,
+    [(); N + M.saturating_sub(N)]:,
+{
+    type Output = Bernstein<T, U, { N + M.saturating_sub(N) }>;
+
+    fn add(self, rhs: Bernstein<T, U, { M }>) -> Self::Output {
+        assert!(
+            self.segm == rhs.segm,
+            "polynomials are defined over different intervals"
+        );
+
+        let lhs: [T; N + M.saturating_sub(N)] = elevate_coef(&self.coef);
+        let rhs: [T; N + M.saturating_sub(N)] = elevate_coef(&rhs.coef);
+
+        Bernstein {
+            segm: self.segm,
+            coef: array::from_fn(|i| lhs[i] + rhs[i]),
+        }
+    }
+}
+
+/// Calculate a difference of two polynomials in the Bernstein basis. If the
+/// sizes of the polynomials are different, both are elevated to the larger size.
+///
+/// # Panics
+/// Panics if the polynomials are defined over different intervals.
+#[cfg(feature = "nightly")]
+impl<T, U, const N: usize, const M: usize> Sub<Bernstein<T, U, { M }>> for Bernstein<T, U, { N }>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<U, Output = T> + Zero,
+    U: Copy + Num + FromPrimitive,
+    [(); N + M.saturating_sub(N)]:,
+{
+    type Output = Bernstein<T, U, { N + M.saturating_sub(N) }>;
+
+    fn sub(self, rhs: Bernstein<T, U, { M }>) -> Self::Output {
+        assert!(
+            self.segm == rhs.segm,
+            "polynomials are defined over different intervals"
+        );
+
+        let lhs: [T; N + M.saturating_sub(N)] = elevate_coef(&self.coef);
+        let rhs: [T; N + M.saturating_sub(N)] = elevate_coef(&rhs.coef);
+
+        Bernstein {
+            segm: self.segm,
+            coef: array::from_fn(|i| lhs[i] - rhs[i]),
+        }
+    }
+}
+
+/// Add a polynomial of the same or smaller size in place. The smaller
+/// polynomial is elevated to the size of `self`.
+///
+/// # Panics
+/// Panics if the polynomials are defined over different intervals.
+#[cfg(feature = "nightly")]
+impl<T, U, const N: usize, const M: usize> AddAssign<Bernstein<T, U, { M }>>
+    for Bernstein<T, U, { N }>
+where
+    T: Copy + Add<Output = T> + Mul<U, Output = T> + Zero,
+    U: Copy + Num + FromPrimitive,
+    [(); N - M]:,
+{
+    fn add_assign(&mut self, rhs: Bernstein<T, U, { M }>) {
+        assert!(
+            self.segm == rhs.segm,
+            "polynomials are defined over different intervals"
+        );
+
+        let rhs: [T; N] = elevate_coef(&rhs.coef);
+        for (c, r) in self.coef.iter_mut().zip(rhs) {
+            *c = *c + r;
+        }
+    }
+}
+
+/// Subtract a polynomial of the same or smaller size in place. The smaller
+/// polynomial is elevated to the size of `self`.
+///
+/// # Panics
+/// Panics if the polynomials are defined over different intervals.
+#[cfg(feature = "nightly")]
+impl<T, U, const N: usize, const M: usize> SubAssign<Bernstein<T, U, { M }>>
+    for Bernstein<T, U, { N }>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<U, Output = T> + Zero,
+    U: Copy + Num + FromPrimitive,
+    [(); N - M]:,
+{
+    fn sub_assign(&mut self, rhs: Bernstein<T, U, { M }>) {
+        assert!(
+            self.segm == rhs.segm,
+            "polynomials are defined over different intervals"
+        );
+
+        let rhs: [T; N] = elevate_coef(&rhs.coef);
+        for (c, r) in self.coef.iter_mut().zip(rhs) {
+            *c = *c - r;
+        }
+    }
+}
+
+/// Negate all coefficients of a polynomial in the Bernstein basis.
+impl<T, U, const N: usize> Neg for Bernstein<T, U, N>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Bernstein<T, U, N>;
+
+    fn neg(self) -> Self::Output {
+        Bernstein {
+            segm: self.segm,
+            coef: self.coef.map(|c| -c),
+        }
     }
-    T::zero()
 }
 
 /// Calculate a product of two polynomials in the Bernstein basis.
@@ -47,10 +302,14 @@ where
 /// See R. T. Farouki, "Pythagorean-Hodograph Curves: Algebra and Geometry
 /// Inseparable", Geometry and Computing (Eds. H. Edelsbrunner, K. Polthier,
 /// and L Kobbelt) Springer (2008). -- p. 258, Sec. 11.7, Eq. (11.20).
+///
+/// # Panics
+/// Panics if the polynomials are defined over different intervals.
+#[cfg(feature = "nightly")]
 impl<T, U, const N: usize, const M: usize> Mul<Bernstein<T, U, { M }>> for Bernstein<T, U, { N }>
 where
     T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Mul<U, Output = T> + Zero,
-    U: Num + FromPrimitive,
+    U: Copy + Num + FromPrimitive,
     [(); N]:,
     [(); M]:,
     [(); N + M - 1]:,
@@ -58,21 +317,12 @@ where
     type Output = Bernstein<T, U, { N + M - 1 }>;
 
     fn mul(self, rhs: Bernstein<T, U, { M }>) -> Self::Output {
-        let mut coef = [T::zero(); N + M - 1];
-
-        let n = M - 1;
-        let m = N - 1;
-
-        for k in 0..=m + n {
-            for j in low_bound(k, n)..=std::cmp::min(m, k) {
-                coef[k] = coef[k]
-                    + self.coef[j]
-                        * rhs.coef[k - j]
-                        * (U::from_usize(binom(m, j)).unwrap()
-                            * U::from_usize(binom(n, k - j)).unwrap()
-                            / U::from_usize(binom(m + n, k)).unwrap());
-            }
-        }
+        assert!(
+            self.segm == rhs.segm,
+            "polynomials are defined over different intervals"
+        );
+
+        let coef = product_coef::<U, _, _, _, _, N, M, _>(&self.coef, &rhs.coef, |a, b| a * b);
 
         Bernstein {
             segm: self.segm,
@@ -89,6 +339,7 @@ where
     [(); N]:,
 {
     type Output = Bernstein<T, U, N>;
+    #[allow(clippy::needless_range_loop)]
     fn mul(self, rhs: W) -> Self::Output {
         let mut coef = self.coef;
         for i in 0..N {
@@ -132,31 +383,9 @@ mod tests {
     use super::*;
 
     #[test]
-    fn binom_n_zero() {
-        assert_eq!(binom(0, 0), 1);
-        assert_eq!(binom(0, 1), 0);
-    }
-
-    #[test]
-    fn binom_n_k() {
-        assert_eq!(binom(7, 0), 1);
-        assert_eq!(binom(7, 1), 7);
-        assert_eq!(binom(7, 2), 21);
-        assert_eq!(binom(7, 3), 35);
-        assert_eq!(binom(7, 4), 35);
-        assert_eq!(binom(7, 5), 21);
-        assert_eq!(binom(7, 6), 7);
-        assert_eq!(binom(7, 7), 1);
-        assert_eq!(binom(7, 8), 0);
-
-        assert_eq!(binom(6, 0), 1);
-        assert_eq!(binom(6, 1), 6);
-        assert_eq!(binom(6, 2), 15);
-        assert_eq!(binom(6, 3), 20);
-        assert_eq!(binom(6, 4), 15);
-        assert_eq!(binom(6, 5), 6);
-        assert_eq!(binom(6, 6), 1);
-        assert_eq!(binom(6, 7), 0);
+    fn elevate_coef_linear() {
+        let coef: [f64; 3] = elevate_coef::<f64, f64, 3>(&[1.0, 3.0]);
+        assert_eq!(coef, [1.0, 2.0, 3.0]);
     }
 
     #[test]
@@ -0,0 +1,249 @@
+//! Rational polynomials in the Bernstein basis (rational Bézier curves).
+
+use num::complex::Complex;
+use num::{Float, FromPrimitive, Num, Zero};
+use std::array;
+use std::ops::{Add, Mul, Sub};
+
+use crate::ops::{diff_coef, elevate_coef, product_slice};
+use crate::Bernstein;
+
+/// Contains control points of type `T` and their weights of type `U` of a
+/// rational polynomial in the Bernstein basis over an interval `(a, b)` of the
+/// parameter of type `U`, which is (0, 1) by default. The number of dimensions
+/// in the basis is `N` which implies that the order of the basis polynomials
+/// in `N - 1`.
+#[derive(Debug, Clone, Copy)]
+pub struct RationalBernstein<T, U, const N: usize> {
+    coef: [T; N],
+    weight: [U; N],
+    segm: (U, U),
+}
+
+impl<T, U, const N: usize> RationalBernstein<T, U, N>
+where
+    U: Num,
+{
+    /// Create new instance of a rational Bernstein polynomial from arrays of
+    /// control points and their weights over the default interval (0, 1).
+    pub fn new(coef: [T; N], weight: [U; N]) -> RationalBernstein<T, U, N> {
+        RationalBernstein {
+            segm: (U::zero(), U::one()),
+            coef,
+            weight,
+        }
+    }
+
+    /// Create new instance of a rational Bernstein polynomial from arrays of
+    /// control points and their weights over the interval (a, b).
+    ///
+    /// # Panics
+    /// Panics if the interval has zero length, i.e. `a == b`.
+    pub fn with_interval(coef: [T; N], weight: [U; N], a: U, b: U) -> RationalBernstein<T, U, N> {
+        assert!(a != b, "interval has zero length");
+        RationalBernstein {
+            segm: (a, b),
+            coef,
+            weight,
+        }
+    }
+
+    /// Return an array of control points.
+    pub fn coef(&self) -> &[T; N] {
+        &self.coef
+    }
+
+    /// Return an array of weights of the control points.
+    pub fn weight(&self) -> &[U; N] {
+        &self.weight
+    }
+
+    /// Return the interval (a, b) over which the polynomial is defined.
+    pub fn interval(&self) -> (U, U)
+    where
+        U: Copy,
+    {
+        self.segm
+    }
+}
+
+impl<T, U, const N: usize> RationalBernstein<T, U, N>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T>,
+    U: Copy + Num + FromPrimitive,
+{
+    /// Create new instance from the polynomial `num` with coefficients that
+    /// are weighted control points `w[i] * p[i]`, and the polynomial `den` of
+    /// weights `w[i]` (homogeneous coordinates). Control points are found by
+    /// the division by their weights, so that zero weights (points at
+    /// infinity) are not supported, and give infinite or NaN control points
+    /// for the floating point types.
+    ///
+    /// # Panics
+    /// Panics if the polynomials are defined over different intervals, or if
+    /// a weight is zero for the exact types, e.g. the rationals.
+    pub fn from_homogeneous(
+        num: Bernstein<T, U, N>,
+        den: Bernstein<U, U, N>,
+    ) -> RationalBernstein<T, U, N> {
+        assert!(
+            num.segm == den.segm,
+            "polynomials are defined over different intervals"
+        );
+
+        RationalBernstein {
+            segm: num.segm,
+            coef: array::from_fn(|i| num.coef[i] * (U::one() / den.coef[i])),
+            weight: den.coef,
+        }
+    }
+
+    /// Return the pair of polynomials in the Bernstein basis of weighted
+    /// control points `w[i] * p[i]`, and of weights `w[i]` (homogeneous
+    /// coordinates).
+    pub fn to_homogeneous(&self) -> (Bernstein<T, U, N>, Bernstein<U, U, N>) {
+        (
+            Bernstein {
+                segm: self.segm,
+                coef: array::from_fn(|i| self.coef[i] * self.weight[i]),
+            },
+            Bernstein {
+                segm: self.segm,
+                coef: self.weight,
+            },
+        )
+    }
+
+    /// Evaluate rational polynomial at the point `u` within its interval
+    /// `a <= u <= b` using the De Casteljau's algorithm in homogeneous
+    /// coordinates.
+    ///
+    /// See Piegl & Tiller. "The NURBS book". Springer Science & Business Media
+    /// (2012) -- Sec. 1.4.
+    pub fn eval(&self, u: U) -> T {
+        let (num, den) = self.to_homogeneous();
+        num.eval(u) * (U::one() / den.eval(u))
+    }
+
+    /// Split the rational polynomial at the point `u` into two rational
+    /// polynomials of the same size that are defined over the intervals to the
+    /// left and to the right of `u`, respectively.
+    ///
+    /// # Panics
+    /// Panics if `u` is an end of the interval.
+    pub fn split(&self, u: U) -> (RationalBernstein<T, U, N>, RationalBernstein<T, U, N>) {
+        let (num, den) = self.to_homogeneous();
+        let (num_left, num_right) = num.split(u);
+        let (den_left, den_right) = den.split(u);
+
+        (
+            RationalBernstein::from_homogeneous(num_left, den_left),
+            RationalBernstein::from_homogeneous(num_right, den_right),
+        )
+    }
+
+    /// Return new instance that is a derivative of the original rational
+    /// polynomial, which is calculated by the quotient rule. The derivative
+    /// is a rational polynomial with the weights that are the coefficients of
+    /// the squared denominator.
+    ///
+    /// See G. Farin, "Curves and Surfaces for CAGD: A Practical Guide",
+    /// Morgan Kaufmann (2002).
+    #[cfg(feature = "nightly")]
+    pub fn diff(&self) -> RationalBernstein<T, U, { 2 * N - 1 }>
+    where
+        T: Zero,
+        [(); 2 * N - 1]:,
+    {
+        self.diff_into()
+    }
+
+    /// Return new instance that is a derivative of the original rational
+    /// polynomial of the explicitly given size `M = 2 * N - 1`.
+    ///
+    /// # Panics
+    /// Panics if `M != 2 * N - 1`.
+    pub fn diff_into<const M: usize>(&self) -> RationalBernstein<T, U, M>
+    where
+        T: Zero,
+    {
+        assert!(M + 1 == 2 * N);
+
+        let (num, den) = self.to_homogeneous();
+        let h = self.segm.1 - self.segm.0;
+
+        // Numerator of the quotient rule: `num' * den - num * den'`.
+        let lhs = product_slice::<U, _, _, _, _>(&diff_coef(&num.coef, h), &den.coef, |p, w| p * w);
+        let rhs = product_slice::<U, _, _, _, _>(&num.coef, &diff_coef(&den.coef, h), |p, w| p * w);
+        let diff: Vec<T> = lhs.iter().zip(&rhs).map(|(l, r)| *l - *r).collect();
+
+        // Derivative of the constant is zero.
+        let num: [T; M] = if diff.is_empty() {
+            [T::zero(); M]
+        } else {
+            elevate_coef(&diff)
+        };
+        let den = product_slice::<U, _, _, _, _>(&den.coef, &den.coef, |v, w| v * w);
+
+        RationalBernstein::from_homogeneous(
+            Bernstein {
+                segm: self.segm,
+                coef: num,
+            },
+            Bernstein {
+                segm: self.segm,
+                coef: array::from_fn(|i| den[i]),
+            },
+        )
+    }
+}
+
+impl<T, U> RationalBernstein<T, U, 3>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T>,
+    U: Copy + Num + FromPrimitive,
+{
+    /// Create new instance of a conic section as a rational quadratic
+    /// polynomial with the control points `p0`, `p1`, `p2`, where the end
+    /// points have unit weights, and `w` is the weight of the middle point.
+    /// The conic is an ellipse for `w < 1`, a parabola for `w = 1`, and a
+    /// hyperbola for `w > 1`.
+    ///
+    /// See Piegl & Tiller. "The NURBS book". Springer Science & Business Media
+    /// (2012) -- Ch. 7.
+    pub fn conic(p0: T, p1: T, p2: T, w: U) -> RationalBernstein<T, U, 3> {
+        RationalBernstein::new([p0, p1, p2], [U::one(), w, U::one()])
+    }
+}
+
+impl<U> RationalBernstein<Complex<U>, U, 3>
+where
+    U: Float + FromPrimitive,
+{
+    /// Create new instance of a circular arc in the complex plane with the
+    /// `center` and the `radius`, that starts at the angle `start` and sweeps
+    /// the angle `sweep` counterclockwise, where `|sweep| < pi`.
+    ///
+    /// See Piegl & Tiller. "The NURBS book". Springer Science & Business Media
+    /// (2012) -- Ch. 7.
+    ///
+    /// # Panics
+    /// Panics if `|sweep| >= pi`.
+    pub fn circular_arc(
+        center: Complex<U>,
+        radius: U,
+        start: U,
+        sweep: U,
+    ) -> RationalBernstein<Complex<U>, U, 3> {
+        assert!(sweep.abs() < U::from_f64(std::f64::consts::PI).unwrap());
+
+        let half = sweep / U::from_usize(2).unwrap();
+        let w = half.cos();
+
+        let p0 = center + Complex::from_polar(radius, start);
+        let p1 = center + Complex::from_polar(radius / w, start + half);
+        let p2 = center + Complex::from_polar(radius, start + sweep);
+
+        RationalBernstein::conic(p0, p1, p2, w)
+    }
+}
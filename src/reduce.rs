@@ -0,0 +1,140 @@
+//! Degree reduction of polynomials in the Bernstein basis.
+
+use num::{FromPrimitive, Num, Zero};
+use std::array;
+use std::ops::{Add, Mul, Sub};
+
+use crate::binomial::{binomial_as, binomial_row};
+use crate::linalg::solve;
+use crate::ops::elevate_coef;
+use crate::{Bernstein, Norm};
+
+// Number of points per coefficient where the approximation error is sampled.
+const SAMPLES: usize = 16;
+
+/// Constraints at the end points of the interval that are imposed on the
+/// polynomial of reduced degree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Constraint {
+    /// Unconstrained least-squares approximation.
+    Free,
+    /// Interpolate values at the end points.
+    C0,
+    /// Interpolate values and first derivatives at the end points.
+    C1,
+}
+
+impl<T, U, const N: usize> Bernstein<T, U, N>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T> + Zero + Norm<U>,
+    U: Copy + Num + FromPrimitive + PartialOrd,
+{
+    /// Return the best approximation of the polynomial in the L2 sense over
+    /// its interval by a polynomial in the Bernstein basis of a smaller size
+    /// `M <= N` (degree reduction), subject to the end point `constraint`.
+    ///
+    /// The second element of the returned pair is the maximum approximation
+    /// error, i.e. the maximum norm of the difference between the original
+    /// and the reduced polynomials, which is sampled at `16 * N + 1` evenly
+    /// spaced points of the interval including the end points. It is zero if
+    /// the polynomial is a degree elevation of a polynomial of the size `M`.
+    ///
+    /// See M. Eck, "Least squares degree reduction of Bézier curves",
+    /// Computer-Aided Design, 27(11) (1995).
+    ///
+    /// # Panics
+    /// Panics if `M > N`, or if `M` is too small to satisfy the `constraint`,
+    /// i.e. `M < 2` for [`Constraint::C0`] and `M < 4` for [`Constraint::C1`].
+    #[allow(clippy::needless_range_loop)]
+    pub fn reduce_to<const M: usize>(&self, constraint: Constraint) -> (Bernstein<T, U, M>, U) {
+        assert!(M <= N);
+        assert!(M > 0);
+
+        let n = N - 1;
+        let m = M - 1;
+
+        // Gram matrix of the Bernstein basis of the size `M`, and projections
+        // of the original polynomial onto this basis. The common factor that
+        // is the length of the interval is omitted.
+        let mut gram = [[U::zero(); M]; M];
+        let mut proj = [T::zero(); M];
+
+        let row_m: [U; M] = binomial_row();
+        let row_n: [U; N] = binomial_row();
+        let row_mm: Vec<U> = (0..=2 * m).map(|k| binomial_as(2 * m, k)).collect();
+        let row_mn: Vec<U> = (0..=m + n).map(|k| binomial_as(m + n, k)).collect();
+        let len_mm = U::from_usize(2 * m + 1).unwrap();
+        let len_mn = U::from_usize(m + n + 1).unwrap();
+
+        for i in 0..=m {
+            for j in 0..=m {
+                gram[i][j] = row_m[i] * row_m[j] / (len_mm * row_mm[i + j]);
+            }
+            for j in 0..=n {
+                proj[i] = proj[i] + self.coef[j] * (row_m[i] * row_n[j] / (len_mn * row_mn[i + j]));
+            }
+        }
+
+        // Values of the coefficients fixed by the constraint.
+        let mut fixed: [Option<T>; M] = [None; M];
+        match constraint {
+            Constraint::Free => {}
+            Constraint::C0 => {
+                assert!(M >= 2);
+                fixed[0] = Some(self.coef[0]);
+                fixed[m] = Some(self.coef[n]);
+            }
+            Constraint::C1 => {
+                assert!(M >= 4);
+                let ratio = U::from_usize(n).unwrap() / U::from_usize(m).unwrap();
+                fixed[0] = Some(self.coef[0]);
+                fixed[1] = Some(self.coef[0] + (self.coef[1] - self.coef[0]) * ratio);
+                fixed[m - 1] = Some(self.coef[n] - (self.coef[n] - self.coef[n - 1]) * ratio);
+                fixed[m] = Some(self.coef[n]);
+            }
+        }
+
+        // Move known coefficients to the right hand side, and replace their
+        // equations with identities.
+        for k in 0..=m {
+            if let Some(v) = fixed[k] {
+                for i in 0..=m {
+                    if fixed[i].is_none() {
+                        proj[i] = proj[i] - v * gram[i][k];
+                    }
+                }
+            }
+        }
+        for k in 0..=m {
+            if let Some(v) = fixed[k] {
+                for j in 0..=m {
+                    gram[k][j] = U::zero();
+                    gram[j][k] = U::zero();
+                }
+                gram[k][k] = U::one();
+                proj[k] = v;
+            }
+        }
+
+        let reduced = Bernstein {
+            segm: self.segm,
+            coef: solve(gram, proj),
+        };
+
+        let elevated: [T; N] = elevate_coef(&reduced.coef);
+        let diff: Bernstein<T, U, N> = Bernstein {
+            segm: self.segm,
+            coef: array::from_fn(|i| self.coef[i] - elevated[i]),
+        };
+
+        let mut err = U::zero();
+        for d in diff.sample(SAMPLES * N + 1) {
+            let d = d.norm();
+            if d > err {
+                err = d;
+            }
+        }
+
+        (reduced, err)
+    }
+}
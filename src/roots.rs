@@ -0,0 +1,127 @@
+//! Real roots of scalar polynomials in the Bernstein basis.
+
+use num::{Float, FromPrimitive};
+
+use crate::Bernstein;
+
+impl<U, const N: usize> Bernstein<U, U, N>
+where
+    U: Float + FromPrimitive,
+{
+    /// Find all real roots of the polynomial within its interval to the
+    /// tolerance `tol`, and return them in ascending order as pairs of the
+    /// root and an upper bound of its multiplicity. The identically zero
+    /// polynomial has no roots by convention.
+    ///
+    /// Roots are isolated by the recursive De Casteljau subdivision, where
+    /// the number of sign changes in the coefficients is an upper bound for
+    /// the number of roots in the interval (Descartes' rule of signs), until
+    /// the interval is smaller than `tol`. Multiple roots, as well as clusters
+    /// of roots that are closer than `tol`, are reported as a single root with
+    /// the number of sign changes over the final interval. It is equal to the
+    /// multiplicity for the exact multiple roots, and to the total
+    /// multiplicity for the clusters of real roots, but also counts pairs of
+    /// complex roots closer than `tol` to the interval. Roots at the end
+    /// points of the interval and at the points of subdivision are counted
+    /// exactly by the vanishing coefficients.
+    ///
+    /// See J. M. Lane & R. F. Riesenfeld, "Bounds on a polynomial", BIT
+    /// Numerical Mathematics, 21(1) (1981).
+    pub fn roots(&self, tol: U) -> Vec<(U, usize)> {
+        let mut roots = Vec::new();
+
+        if self.coef.iter().all(|c| c.is_zero()) {
+            return roots;
+        }
+
+        let head = self.coef.iter().take_while(|c| c.is_zero()).count();
+        if head > 0 {
+            roots.push((self.segm.0, head));
+        }
+
+        isolate(self, tol, &mut roots);
+
+        let tail = self.coef.iter().rev().take_while(|c| c.is_zero()).count();
+        if tail > 0 {
+            roots.push((self.segm.1, tail));
+        }
+
+        // Merge clusters of roots that are closer than `tol`.
+        roots.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap());
+        let mut merged: Vec<(U, usize)> = Vec::with_capacity(roots.len());
+        for (r, m) in roots {
+            match merged.last_mut() {
+                Some((s, k)) if (r - *s).abs() <= tol => {
+                    *s = (*s * U::from_usize(*k).unwrap() + r * U::from_usize(m).unwrap())
+                        / U::from_usize(*k + m).unwrap();
+                    *k += m;
+                }
+                _ => merged.push((r, m)),
+            }
+        }
+
+        merged
+    }
+}
+
+// Number of sign changes in the sequence of coefficients ignoring zeros.
+fn sign_changes<U: Float>(coef: &[U]) -> usize {
+    let mut changes = 0;
+    let mut last = U::zero();
+
+    for &c in coef {
+        if c.is_zero() {
+            continue;
+        }
+        if !last.is_zero() && (c < U::zero()) != (last < U::zero()) {
+            changes += 1;
+        }
+        last = c;
+    }
+
+    changes
+}
+
+// Recursively isolate roots in the open interval of the polynomial. Roots
+// at the internal points of subdivision are reported by the right piece.
+fn isolate<U, const N: usize>(p: &Bernstein<U, U, N>, tol: U, roots: &mut Vec<(U, usize)>)
+where
+    U: Float + FromPrimitive,
+{
+    // Upper bound of the number of roots in the interval counted with their
+    // multiplicities.
+    let bound = sign_changes(&p.coef);
+    if bound == 0 {
+        return;
+    }
+
+    let (a, b) = p.segm;
+    let mid = (a + b) / U::from_usize(2).unwrap();
+
+    if (b - a).abs() <= tol || mid == a || mid == b {
+        roots.push((mid, bound));
+        return;
+    }
+
+    let (left, right) = p.split(mid);
+    isolate(&left, tol, roots);
+
+    let head = right.coef.iter().take_while(|c| c.is_zero()).count();
+    if head > 0 && head < N {
+        roots.push((mid, head));
+    }
+    isolate(&right, tol, roots);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_changes_with_zeros() {
+        assert_eq!(sign_changes(&[1.0, 2.0, 3.0]), 0);
+        assert_eq!(sign_changes(&[1.0, -2.0, 3.0]), 2);
+        assert_eq!(sign_changes(&[0.0, -2.0, 0.0, 3.0]), 1);
+        assert_eq!(sign_changes(&[0.0, 0.0]), 0);
+    }
+}
@@ -0,0 +1,178 @@
+//! Evaluation of polynomials in the Bernstein basis at many points.
+
+use num::{FromPrimitive, Num};
+use std::array;
+use std::ops::{Add, Mul, Sub};
+
+use crate::Bernstein;
+
+impl<T, U, const N: usize> Bernstein<T, U, N>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T>,
+    U: Copy + Num + FromPrimitive,
+{
+    /// Evaluate polynomial at each of the points `u` and write the values
+    /// into `out` using the De Casteljau's algorithm, where the interval of
+    /// the polynomial is only processed once.
+    ///
+    /// # Panics
+    /// Panics if `u` and `out` have different lengths.
+    pub fn eval_many(&self, u: &[U], out: &mut [T]) {
+        assert!(u.len() == out.len());
+
+        let (a, b) = self.segm;
+        let h = U::one() / (b - a);
+
+        for (x, y) in u.iter().zip(out.iter_mut()) {
+            let s = (*x - a) * h;
+            let t = (b - *x) * h;
+
+            let mut q = self.coef;
+            for k in 1..N {
+                for i in 0..N - k {
+                    q[i] = q[i] * t + q[i + 1] * s;
+                }
+            }
+            *y = q[0];
+        }
+    }
+
+    /// Return an iterator over the values of the polynomial at `n` evenly
+    /// spaced points of its interval, including both end points.
+    pub fn sample(&self, n: usize) -> Sample<T, U, N> {
+        Sample {
+            poly: *self,
+            step: step(self.segm, n),
+            k: 0,
+            n,
+        }
+    }
+
+    /// Return an iterator over the values of the polynomial at `n` evenly
+    /// spaced points of its interval, including both end points, that are
+    /// calculated by the forward differencing. Each value costs only `N - 1`
+    /// additions, but the rounding errors accumulate with the number of
+    /// points, so that the values are less accurate than the ones of
+    /// [`Bernstein::sample`].
+    ///
+    /// See G. Farin, "Curves and Surfaces for CAGD: A Practical Guide",
+    /// Morgan Kaufmann (2002).
+    ///
+    /// # Panics
+    /// Panics if `N == 0`.
+    pub fn forward_differences(&self, n: usize) -> ForwardDifferences<T, N> {
+        assert!(N > 0);
+
+        let h = step(self.segm, n);
+
+        // Forward differences of all orders at the start of the interval from
+        // the values at the first `N` points.
+        let mut diffs: [T; N] =
+            array::from_fn(|k| self.eval(self.segm.0 + h * U::from_usize(k).unwrap()));
+        for r in 1..N {
+            for i in (r..N).rev() {
+                diffs[i] = diffs[i] - diffs[i - 1];
+            }
+        }
+
+        ForwardDifferences { diffs, k: 0, n }
+    }
+}
+
+// Distance between `n` evenly spaced points of the interval.
+fn step<U>(segm: (U, U), n: usize) -> U
+where
+    U: Copy + Num + FromPrimitive,
+{
+    if n < 2 {
+        return U::zero();
+    }
+    (segm.1 - segm.0) / U::from_usize(n - 1).unwrap()
+}
+
+/// Iterator over the values of a polynomial in the Bernstein basis at evenly
+/// spaced points, which is created by [`Bernstein::sample`].
+#[derive(Debug, Clone)]
+pub struct Sample<T, U, const N: usize> {
+    poly: Bernstein<T, U, N>,
+    step: U,
+    k: usize,
+    n: usize,
+}
+
+impl<T, U, const N: usize> Iterator for Sample<T, U, N>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T>,
+    U: Copy + Num + FromPrimitive,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.k >= self.n {
+            return None;
+        }
+
+        // The last point is exactly the end of the interval.
+        let u = if self.k > 0 && self.k + 1 == self.n {
+            self.poly.segm.1
+        } else {
+            self.poly.segm.0 + self.step * U::from_usize(self.k).unwrap()
+        };
+        self.k += 1;
+
+        Some(self.poly.eval(u))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rest = self.n - self.k;
+        (rest, Some(rest))
+    }
+}
+
+impl<T, U, const N: usize> ExactSizeIterator for Sample<T, U, N>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T>,
+    U: Copy + Num + FromPrimitive,
+{
+}
+
+/// Iterator over the values of a polynomial in the Bernstein basis at evenly
+/// spaced points by the forward differencing, which is created by
+/// [`Bernstein::forward_differences`].
+#[derive(Debug, Clone)]
+pub struct ForwardDifferences<T, const N: usize> {
+    diffs: [T; N],
+    k: usize,
+    n: usize,
+}
+
+impl<T, const N: usize> Iterator for ForwardDifferences<T, N>
+where
+    T: Copy + Add<T, Output = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.k >= self.n {
+            return None;
+        }
+        self.k += 1;
+
+        let value = self.diffs[0];
+        for i in 1..N {
+            self.diffs[i - 1] = self.diffs[i - 1] + self.diffs[i];
+        }
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rest = self.n - self.k;
+        (rest, Some(rest))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ForwardDifferences<T, N> where
+    T: Copy + Add<T, Output = T>
+{
+}
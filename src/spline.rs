@@ -0,0 +1,308 @@
+//! Piecewise polynomials in the Bernstein basis (Bézier splines).
+
+use num::{FromPrimitive, Num, Zero};
+use std::ops::{Add, Mul, Sub};
+
+use crate::{Bernstein, Norm};
+
+/// Continuity conditions at the joints of a piecewise polynomial curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Continuity {
+    /// Continuity of the curve.
+    C0,
+    /// Continuity of the curve and its first derivative.
+    C1,
+    /// Continuity of the curve and the direction of its first derivative.
+    G1,
+    /// Continuity of the curve and its first and second derivatives.
+    C2,
+}
+
+/// Contains a sequence of polynomials of type `T` in the Bernstein basis of
+/// the same size `N`, which are defined over consecutive intervals of the
+/// parameter of type `U` (segments of a piecewise polynomial curve).
+#[derive(Debug, Clone)]
+pub struct BezierSpline<T, U, const N: usize> {
+    segments: Vec<Bernstein<T, U, N>>,
+}
+
+impl<T, U, const N: usize> BezierSpline<T, U, N>
+where
+    U: Copy + Num + PartialOrd,
+{
+    /// Create new instance of a piecewise polynomial from the sequence of
+    /// its segments.
+    ///
+    /// # Panics
+    /// Panics if there are no segments, or if the intervals of the segments
+    /// are not consecutive, i.e. each interval does not start where the
+    /// previous one ends.
+    pub fn new(segments: Vec<Bernstein<T, U, N>>) -> BezierSpline<T, U, N> {
+        assert!(!segments.is_empty());
+        for k in 1..segments.len() {
+            assert!(
+                segments[k - 1].segm.1 == segments[k].segm.0,
+                "intervals of segments are not consecutive"
+            );
+        }
+
+        BezierSpline { segments }
+    }
+
+    /// Create new instance of a piecewise polynomial from the coefficients of
+    /// its segments in the Bernstein basis, and the break points of the
+    /// parameter, so that the segment `k` is defined over the interval
+    /// `(breaks[k], breaks[k + 1])`.
+    ///
+    /// # Panics
+    /// Panics if there are no segments, if the number of break points is not
+    /// the number of segments plus one, or if two consecutive break points
+    /// are equal.
+    pub fn from_coef(coef: Vec<[T; N]>, breaks: &[U]) -> BezierSpline<T, U, N> {
+        assert!(breaks.len() == coef.len() + 1);
+
+        BezierSpline::new(
+            coef.into_iter()
+                .enumerate()
+                .map(|(k, c)| Bernstein::with_interval(c, breaks[k], breaks[k + 1]))
+                .collect(),
+        )
+    }
+
+    /// Return the segments of the piecewise polynomial.
+    pub fn segments(&self) -> &[Bernstein<T, U, N>] {
+        &self.segments
+    }
+
+    /// Return the interval (a, b) over which the piecewise polynomial is
+    /// defined.
+    pub fn interval(&self) -> (U, U) {
+        (
+            self.segments[0].segm.0,
+            self.segments[self.segments.len() - 1].segm.1,
+        )
+    }
+
+    /// Return the index of the segment that contains the point `u`. Points
+    /// at the joints belong to the segment to the left, and points outside of
+    /// the interval belong to the first or the last segments.
+    pub fn segment_index(&self, u: U) -> usize {
+        let k = self.segments.partition_point(|s| s.segm.1 < u);
+        std::cmp::min(k, self.segments.len() - 1)
+    }
+
+    /// Return the segment that contains the point `u`.
+    pub fn segment(&self, u: U) -> &Bernstein<T, U, N> {
+        &self.segments[self.segment_index(u)]
+    }
+}
+
+impl<T, U, const N: usize> BezierSpline<T, U, N>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<U, Output = T>,
+    U: Copy + Num + FromPrimitive + PartialOrd,
+{
+    /// Evaluate the piecewise polynomial at the point `u` within its interval.
+    pub fn eval(&self, u: U) -> T {
+        self.segment(u).eval(u)
+    }
+
+    /// Return new instance that is a derivative of the original piecewise
+    /// polynomial, which is calculated segment-wise.
+    #[cfg(feature = "nightly")]
+    pub fn diff(&self) -> BezierSpline<T, U, { N - 1 }>
+    where
+        [(); N - 1]:,
+    {
+        self.diff_into()
+    }
+
+    /// Return new instance that is a derivative of the original piecewise
+    /// polynomial of the explicitly given size `M = N - 1`.
+    ///
+    /// # Panics
+    /// Panics if `M + 1 != N`.
+    pub fn diff_into<const M: usize>(&self) -> BezierSpline<T, U, M> {
+        BezierSpline {
+            segments: self.segments.iter().map(|s| s.diff_into()).collect(),
+        }
+    }
+
+    /// Return new instance that is an integral of the original piecewise
+    /// polynomial, which is continuous at the joints.
+    ///
+    /// # Arguments
+    /// * `c` - the constant of integration, i.e. the value at the start of
+    ///   the interval.
+    #[cfg(feature = "nightly")]
+    pub fn integ(&self, c: T) -> BezierSpline<T, U, { N + 1 }>
+    where
+        [(); N + 1]:,
+    {
+        self.integ_into(c)
+    }
+
+    /// Return new instance that is an integral of the original piecewise
+    /// polynomial of the explicitly given size `M = N + 1`, which is
+    /// continuous at the joints.
+    ///
+    /// # Panics
+    /// Panics if `M != N + 1`.
+    pub fn integ_into<const M: usize>(&self, c: T) -> BezierSpline<T, U, M> {
+        let mut segments = Vec::with_capacity(self.segments.len());
+        let mut c = c;
+        for s in &self.segments {
+            let i = s.integ_into::<M>(c);
+            c = i.coef[N];
+            segments.push(i);
+        }
+
+        BezierSpline { segments }
+    }
+
+    /// Check if the piecewise polynomial satisfies the `continuity` condition
+    /// at all joints to the tolerance `tol`, where the derivatives are with
+    /// respect to the global parameter.
+    ///
+    /// # Panics
+    /// Panics if `N == 0`.
+    pub fn is_continuous(&self, continuity: Continuity, tol: U) -> bool
+    where
+        T: Zero + Norm<U>,
+    {
+        assert!(N >= 1);
+
+        self.segments.windows(2).all(|pair| {
+            let (left, right) = (&pair[0], &pair[1]);
+
+            if (left.coef[N - 1] - right.coef[0]).norm() > tol {
+                return false;
+            }
+
+            match continuity {
+                Continuity::C0 => true,
+                Continuity::C1 => (first_diff(left, N - 1) - first_diff(right, 0)).norm() <= tol,
+                Continuity::G1 => {
+                    let (l, r) = (first_diff(left, N - 1), first_diff(right, 0));
+                    let (nl, nr) = (l.norm(), r.norm());
+                    if nl.is_zero() || nr.is_zero() {
+                        return nl.is_zero() && nr.is_zero();
+                    }
+                    (l * (U::one() / nl) - r * (U::one() / nr)).norm() <= tol
+                }
+                Continuity::C2 => {
+                    (first_diff(left, N - 1) - first_diff(right, 0)).norm() <= tol
+                        && (second_diff(left, N - 1) - second_diff(right, 0)).norm() <= tol
+                }
+            }
+        })
+    }
+
+    /// Enforce the `continuity` condition at all joints by moving the first
+    /// control points of each segment to match the end of the previous
+    /// segment, so that the first segment is not changed. Derivatives are
+    /// matched with respect to the global parameter. For the
+    /// [`Continuity::G1`] the first derivatives are matched as for the
+    /// [`Continuity::C1`], see [`BezierSpline::enforce_g1`] to preserve the
+    /// lengths of the first legs of the control polygons instead.
+    ///
+    /// # Panics
+    /// Panics if the segments have not enough control points to match the
+    /// derivatives, i.e. `N < 1` for [`Continuity::C0`], `N < 2` for
+    /// [`Continuity::C1`] and [`Continuity::G1`], and `N < 3` for
+    /// [`Continuity::C2`].
+    pub fn enforce(&mut self, continuity: Continuity) {
+        match continuity {
+            Continuity::C0 => assert!(N >= 1),
+            Continuity::C1 | Continuity::G1 => assert!(N >= 2),
+            Continuity::C2 => assert!(N >= 3),
+        }
+
+        for k in 1..self.segments.len() {
+            let left = self.segments[k - 1];
+            let right = &mut self.segments[k];
+            let ratio = (right.segm.1 - right.segm.0) / (left.segm.1 - left.segm.0);
+
+            right.coef[0] = left.coef[N - 1];
+
+            if continuity != Continuity::C0 {
+                right.coef[1] = right.coef[0] + (left.coef[N - 1] - left.coef[N - 2]) * ratio;
+            }
+            if continuity == Continuity::C2 {
+                right.coef[2] = right.coef[1] + right.coef[1] - right.coef[0]
+                    + (left.coef[N - 1] - left.coef[N - 2] - left.coef[N - 2] + left.coef[N - 3])
+                        * (ratio * ratio);
+            }
+        }
+    }
+
+    /// Enforce the [`Continuity::G1`] condition at all joints as
+    /// [`BezierSpline::enforce`], but preserve the lengths of the first legs
+    /// of the control polygons, so that only the directions of the
+    /// derivatives are matched.
+    ///
+    /// # Panics
+    /// Panics if `N < 2`.
+    pub fn enforce_g1(&mut self)
+    where
+        T: Norm<U>,
+    {
+        assert!(N >= 2);
+
+        for k in 1..self.segments.len() {
+            let left = self.segments[k - 1];
+            let right = &mut self.segments[k];
+            let ratio = (right.segm.1 - right.segm.0) / (left.segm.1 - left.segm.0);
+
+            right.coef[0] = left.coef[N - 1];
+
+            let dir = left.coef[N - 1] - left.coef[N - 2];
+            let len = (right.coef[1] - right.coef[0]).norm();
+            let norm = dir.norm();
+            if !norm.is_zero() && !len.is_zero() {
+                right.coef[1] = right.coef[0] + dir * (len / norm);
+            } else {
+                right.coef[1] = right.coef[0] + dir * ratio;
+            }
+        }
+    }
+}
+
+// First derivative of the segment at its coefficient `i`, which is either the
+// first or the last one.
+fn first_diff<T, U, const N: usize>(s: &Bernstein<T, U, N>, i: usize) -> T
+where
+    T: Copy + Sub<T, Output = T> + Mul<U, Output = T> + Zero,
+    U: Copy + Num + FromPrimitive,
+{
+    if N < 2 {
+        return T::zero();
+    }
+
+    let factor = U::from_usize(N - 1).unwrap() / (s.segm.1 - s.segm.0);
+    if i == 0 {
+        (s.coef[1] - s.coef[0]) * factor
+    } else {
+        (s.coef[N - 1] - s.coef[N - 2]) * factor
+    }
+}
+
+// Second derivative of the segment at its coefficient `i`, which is either
+// the first or the last one.
+fn second_diff<T, U, const N: usize>(s: &Bernstein<T, U, N>, i: usize) -> T
+where
+    T: Copy + Sub<T, Output = T> + Mul<U, Output = T> + Zero,
+    U: Copy + Num + FromPrimitive,
+{
+    if N < 3 {
+        return T::zero();
+    }
+
+    let h = s.segm.1 - s.segm.0;
+    let factor = U::from_usize((N - 1) * (N - 2)).unwrap() / (h * h);
+    if i == 0 {
+        (s.coef[2] - s.coef[1] - (s.coef[1] - s.coef[0])) * factor
+    } else {
+        (s.coef[N - 1] - s.coef[N - 2] - (s.coef[N - 2] - s.coef[N - 3])) * factor
+    }
+}
@@ -0,0 +1,194 @@
+//! Traits for the types of coefficients of polynomials in the Bernstein basis.
+
+use num::complex::Complex;
+use num::rational::Ratio;
+use num::{Float, Integer, Num, Signed};
+
+/// Norm of a coefficient of a polynomial, which is used to measure distances
+/// between control points.
+pub trait Norm<U> {
+    /// Return the norm of `self`.
+    fn norm(&self) -> U;
+}
+
+macro_rules! float_norm_impl(
+    ($($U: ty),* $(,)*) => {$(
+        impl Norm<$U> for $U {
+            fn norm(&self) -> $U {
+                self.abs()
+            }
+        }
+    )*}
+);
+
+float_norm_impl!(f32, f64);
+
+impl<T> Norm<Ratio<T>> for Ratio<T>
+where
+    T: Clone + Integer + Signed,
+{
+    fn norm(&self) -> Ratio<T> {
+        self.abs()
+    }
+}
+
+impl<U> Norm<U> for Complex<U>
+where
+    U: Float,
+{
+    fn norm(&self) -> U {
+        Complex::norm(*self)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<U, const D: usize> Norm<U> for nalgebra::SVector<U, D>
+where
+    U: nalgebra::RealField,
+{
+    fn norm(&self) -> U {
+        nalgebra::Matrix::norm(self)
+    }
+}
+
+/// Access to the Cartesian components of a coefficient of a polynomial, which
+/// are used to process curves component-wise.
+pub trait Components<U>: Copy {
+    /// Number of components.
+    const DIM: usize;
+
+    /// Return the component `i < DIM`.
+    fn component(&self, i: usize) -> U;
+
+    /// Return a mutable reference to the component `i < DIM`.
+    fn component_mut(&mut self, i: usize) -> &mut U;
+}
+
+macro_rules! float_components_impl(
+    ($($U: ty),* $(,)*) => {$(
+        impl Components<$U> for $U {
+            const DIM: usize = 1;
+
+            fn component(&self, i: usize) -> $U {
+                assert!(i < 1);
+                *self
+            }
+
+            fn component_mut(&mut self, i: usize) -> &mut $U {
+                assert!(i < 1);
+                self
+            }
+        }
+    )*}
+);
+
+float_components_impl!(f32, f64);
+
+impl<U> Components<U> for Complex<U>
+where
+    U: Copy,
+{
+    const DIM: usize = 2;
+
+    fn component(&self, i: usize) -> U {
+        match i {
+            0 => self.re,
+            1 => self.im,
+            _ => panic!("component index out of range"),
+        }
+    }
+
+    fn component_mut(&mut self, i: usize) -> &mut U {
+        match i {
+            0 => &mut self.re,
+            1 => &mut self.im,
+            _ => panic!("component index out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<U, const D: usize> Components<U> for nalgebra::SVector<U, D>
+where
+    U: nalgebra::Scalar + Copy,
+{
+    const DIM: usize = D;
+
+    fn component(&self, i: usize) -> U {
+        self[i]
+    }
+
+    fn component_mut(&mut self, i: usize) -> &mut U {
+        &mut self[i]
+    }
+}
+
+/// Dot (scalar) product of coefficients of polynomials.
+pub trait Dot<U> {
+    /// Return the dot product of `self` and `rhs`.
+    fn dot(&self, rhs: &Self) -> U;
+}
+
+macro_rules! float_dot_impl(
+    ($($U: ty),* $(,)*) => {$(
+        impl Dot<$U> for $U {
+            fn dot(&self, rhs: &$U) -> $U {
+                self * rhs
+            }
+        }
+    )*}
+);
+
+float_dot_impl!(f32, f64);
+
+impl<U> Dot<U> for Complex<U>
+where
+    U: Copy + Num,
+{
+    fn dot(&self, rhs: &Complex<U>) -> U {
+        self.re * rhs.re + self.im * rhs.im
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<U, const D: usize> Dot<U> for nalgebra::SVector<U, D>
+where
+    U: nalgebra::Scalar + nalgebra::ClosedAdd + nalgebra::ClosedMul + num::Zero,
+{
+    fn dot(&self, rhs: &nalgebra::SVector<U, D>) -> U {
+        nalgebra::Matrix::dot(self, rhs)
+    }
+}
+
+/// Cross product of coefficients of polynomials, which is a scalar in the
+/// complex plane, and a vector in 3D.
+pub trait Cross {
+    /// Type of the cross product.
+    type Output;
+
+    /// Return the cross product of `self` and `rhs`.
+    fn cross(&self, rhs: &Self) -> Self::Output;
+}
+
+impl<U> Cross for Complex<U>
+where
+    U: Copy + Num,
+{
+    type Output = U;
+
+    fn cross(&self, rhs: &Complex<U>) -> U {
+        self.re * rhs.im - self.im * rhs.re
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<U> Cross for nalgebra::Vector3<U>
+where
+    U: nalgebra::Scalar + nalgebra::ClosedAdd + nalgebra::ClosedSub + nalgebra::ClosedMul,
+{
+    type Output = nalgebra::Vector3<U>;
+
+    fn cross(&self, rhs: &nalgebra::Vector3<U>) -> nalgebra::Vector3<U> {
+        nalgebra::Matrix::cross(self, rhs)
+    }
+}
@@ -0,0 +1,139 @@
+//! Products of polynomials in the Bernstein basis with different types of
+//! coefficients, such as dot and cross products of curves.
+
+use num::{FromPrimitive, Num, Zero};
+use std::ops::{Add, Mul};
+
+use crate::ops::product_coef;
+use crate::{Bernstein, Cross, Dot};
+
+impl<T, U, const N: usize> Bernstein<T, U, N>
+where
+    T: Copy,
+    U: Copy + Num + FromPrimitive,
+{
+    /// Calculate a product of two polynomials in the Bernstein basis of the
+    /// explicitly given size `K = N + M - 1`, where the product of the
+    /// coefficients `f` defines the type of the coefficients of the result.
+    ///
+    /// See R. T. Farouki, "Pythagorean-Hodograph Curves: Algebra and Geometry
+    /// Inseparable", Geometry and Computing (Eds. H. Edelsbrunner, K. Polthier,
+    /// and L Kobbelt) Springer (2008). -- p. 258, Sec. 11.7, Eq. (11.20).
+    ///
+    /// # Panics
+    /// Panics if `K + 1 != N + M`, or if the polynomials are defined over
+    /// different intervals.
+    pub fn product_with<B, C, F, const M: usize, const K: usize>(
+        &self,
+        rhs: &Bernstein<B, U, M>,
+        f: F,
+    ) -> Bernstein<C, U, K>
+    where
+        B: Copy,
+        C: Copy + Add<Output = C> + Mul<U, Output = C> + Zero,
+        F: Fn(T, B) -> C,
+    {
+        assert!(
+            self.segm == rhs.segm,
+            "polynomials are defined over different intervals"
+        );
+
+        Bernstein {
+            segm: self.segm,
+            coef: product_coef::<U, _, _, _, _, N, M, K>(&self.coef, &rhs.coef, f),
+        }
+    }
+
+    /// Calculate the dot product of two curves in the Bernstein basis, which
+    /// is a scalar polynomial, e.g. the squared speed `p'(u) . p'(u)`.
+    ///
+    /// # Panics
+    /// Panics if the polynomials are defined over different intervals.
+    #[cfg(feature = "nightly")]
+    pub fn dot<const M: usize>(&self, rhs: &Bernstein<T, U, M>) -> Bernstein<U, U, { N + M - 1 }>
+    where
+        T: Dot<U>,
+    {
+        self.dot_into(rhs)
+    }
+
+    /// Calculate the dot product of two curves in the Bernstein basis of the
+    /// explicitly given size `K = N + M - 1`.
+    ///
+    /// # Panics
+    /// Panics if `K + 1 != N + M`, or if the polynomials are defined over
+    /// different intervals.
+    pub fn dot_into<const M: usize, const K: usize>(
+        &self,
+        rhs: &Bernstein<T, U, M>,
+    ) -> Bernstein<U, U, K>
+    where
+        T: Dot<U>,
+    {
+        self.product_with(rhs, |a, b| a.dot(&b))
+    }
+
+    /// Calculate the cross product of two curves in the Bernstein basis, e.g.
+    /// `p'(u) x p''(u)` for the curvature, which is a scalar polynomial in the
+    /// complex plane, and a vector polynomial in 3D.
+    ///
+    /// # Panics
+    /// Panics if the polynomials are defined over different intervals.
+    #[cfg(feature = "nightly")]
+    pub fn cross<const M: usize>(
+        &self,
+        rhs: &Bernstein<T, U, M>,
+    ) -> Bernstein<T::Output, U, { N + M - 1 }>
+    where
+        T: Cross,
+        T::Output: Copy + Add<Output = T::Output> + Mul<U, Output = T::Output> + Zero,
+    {
+        self.cross_into(rhs)
+    }
+
+    /// Calculate the cross product of two curves in the Bernstein basis of
+    /// the explicitly given size `K = N + M - 1`.
+    ///
+    /// # Panics
+    /// Panics if `K + 1 != N + M`, or if the polynomials are defined over
+    /// different intervals.
+    pub fn cross_into<const M: usize, const K: usize>(
+        &self,
+        rhs: &Bernstein<T, U, M>,
+    ) -> Bernstein<T::Output, U, K>
+    where
+        T: Cross,
+        T::Output: Copy + Add<Output = T::Output> + Mul<U, Output = T::Output> + Zero,
+    {
+        self.product_with(rhs, |a, b| a.cross(&b))
+    }
+
+    /// Calculate the product of the curve and the scalar polynomial `s` in
+    /// the Bernstein basis, e.g. to scale a vector curve by a scalar function.
+    ///
+    /// # Panics
+    /// Panics if the polynomials are defined over different intervals.
+    #[cfg(feature = "nightly")]
+    pub fn scale_by<const M: usize>(&self, s: &Bernstein<U, U, M>) -> Bernstein<T, U, { N + M - 1 }>
+    where
+        T: Add<Output = T> + Mul<U, Output = T> + Zero,
+    {
+        self.scale_by_into(s)
+    }
+
+    /// Calculate the product of the curve and the scalar polynomial `s` in
+    /// the Bernstein basis of the explicitly given size `K = N + M - 1`.
+    ///
+    /// # Panics
+    /// Panics if `K + 1 != N + M`, or if the polynomials are defined over
+    /// different intervals.
+    pub fn scale_by_into<const M: usize, const K: usize>(
+        &self,
+        s: &Bernstein<U, U, M>,
+    ) -> Bernstein<T, U, K>
+    where
+        T: Add<Output = T> + Mul<U, Output = T> + Zero,
+    {
+        self.product_with(s, |a, b| a * b)
+    }
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "nightly")]
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+use bernstein::Bernstein;
+
+use num::complex::Complex64;
+use num::rational::Rational64;
+
+mod routines;
+pub use routines::*;
+
+#[test]
+fn add_same_order() {
+    let p: Bernstein<f64, f64, 3> = Bernstein::new([1.0, 2.0, 3.0]);
+    let q: Bernstein<f64, f64, 3> = Bernstein::new([0.5, -2.0, 1.0]);
+
+    let c = p + q;
+    assert_eq!(c.coef().clone(), [1.5, 0.0, 4.0]);
+
+    let c = p - q;
+    assert_eq!(c.coef().clone(), [0.5, 4.0, 2.0]);
+}
+
+#[test]
+fn add_different_order_rational() {
+    let p: Bernstein<Rational64, Rational64, 2> =
+        Bernstein::new([Rational64::new(1, 2), Rational64::new(-1, 3)]);
+    let q: Bernstein<Rational64, Rational64, 4> = Bernstein::new([
+        Rational64::new(1, 5),
+        Rational64::new(2, 7),
+        Rational64::new(-3, 11),
+        Rational64::new(1, 1),
+    ]);
+
+    let s = p + q;
+    let t = q + p;
+    let d = q - p;
+    assert_eq!(s.coef().len(), 4);
+    assert_eq!(s.coef().clone(), t.coef().clone());
+
+    // Check pointwise at several parameter values.
+    for k in 0..=10 {
+        let u = Rational64::new(k, 10);
+        assert_eq!(s.eval(u), p.eval(u) + q.eval(u));
+        assert_eq!(d.eval(u), q.eval(u) - p.eval(u));
+    }
+}
+
+#[test]
+fn add_assign_complex() {
+    const DBL_TOL: f64 = 1.0e-15;
+    let p0 = Complex64::new(0.0, 1.0);
+    let p1 = Complex64::new(2.0, -1.0);
+    let p2 = Complex64::new(1.0, 1.0);
+
+    let mut c: Bernstein<Complex64, f64, 3> = Bernstein::new([p0, p1, p2]);
+    let l: Bernstein<Complex64, f64, 2> = Bernstein::new([p0, p2]);
+
+    c += l;
+    let coef = *c.coef();
+    assert!(equal_with_abs_tol(coef[0].re, 0.0, DBL_TOL));
+    assert!(equal_with_abs_tol(coef[0].im, 2.0, DBL_TOL));
+    assert!(equal_with_abs_tol(coef[1].re, 2.5, DBL_TOL));
+    assert!(equal_with_abs_tol(coef[1].im, 0.0, DBL_TOL));
+    assert!(equal_with_abs_tol(coef[2].re, 2.0, DBL_TOL));
+    assert!(equal_with_abs_tol(coef[2].im, 2.0, DBL_TOL));
+
+    c -= l;
+    c -= c;
+    for p in c.coef() {
+        assert!(equal_with_abs_tol(p.norm(), 0.0, DBL_TOL));
+    }
+}
+
+#[test]
+fn neg_f64() {
+    let p: Bernstein<f64, f64, 3> = Bernstein::new([1.0, -2.0, 3.0]);
+    assert_eq!((-p).coef().clone(), [-1.0, 2.0, -3.0]);
+    assert_eq!((p + (-p)).coef().clone(), [0.0, 0.0, 0.0]);
+}
+
+#[test]
+#[should_panic]
+fn add_different_intervals() {
+    let p: Bernstein<f64, f64, 2> = Bernstein::new([1.0, 2.0]);
+    let q: Bernstein<f64, f64, 2> = Bernstein::with_interval([1.0, 2.0], 0.0, 2.0);
+    let _ = p + q;
+}
@@ -0,0 +1,144 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+use num::complex::Complex64;
+
+use bernstein::Bernstein;
+
+mod routines;
+pub use routines::*;
+
+const DBL_TOL: f64 = 1.0e-13;
+
+// Quintic Pythagorean-hodograph curve `p' = w^2` over the interval (-1, 2)
+// with its arc length `s(u)` from the start of the interval, which is the
+// integral of the polynomial speed `|w|^2`.
+fn pythagorean_hodograph() -> (Bernstein<Complex64, f64, 6>, Bernstein<f64, f64, 6>) {
+    let w: Bernstein<Complex64, f64, 3> = Bernstein::with_interval(
+        [
+            Complex64::new(1.0, 0.5),
+            Complex64::new(0.2, -0.3),
+            Complex64::new(-0.5, 1.0),
+        ],
+        -1.0,
+        2.0,
+    );
+
+    let p = w
+        .mul_into::<3, 5>(&w)
+        .integ_into::<6>(Complex64::new(-1.0, 0.0));
+    let s = w.dot_into::<3, 5>(&w).integ_into::<6>(0.0);
+    (p, s)
+}
+
+#[test]
+fn arc_length_pythagorean_hodograph() {
+    let (p, s) = pythagorean_hodograph();
+
+    assert!(equal_with_abs_tol(
+        p.arc_length(-1.0, 2.0),
+        s.eval(2.0),
+        DBL_TOL
+    ));
+    for k in 0..=12 {
+        let a = -1.0 + 0.25 * k as f64;
+        for j in 0..=12 {
+            let b = -1.0 + 0.25 * j as f64;
+            assert!(equal_with_abs_tol(
+                p.arc_length(a, b),
+                s.eval(b) - s.eval(a),
+                DBL_TOL
+            ));
+        }
+    }
+}
+
+#[test]
+fn param_at_length_pythagorean_hodograph() {
+    let (p, s) = pythagorean_hodograph();
+
+    for k in 0..=30 {
+        let u = -1.0 + 0.1 * k as f64;
+        assert!(equal_with_abs_tol(p.param_at_length(s.eval(u)), u, 1.0e-12));
+    }
+
+    // Lengths outside of the curve are clamped.
+    assert_eq!(p.param_at_length(-1.0), -1.0);
+    assert!(equal_with_abs_tol(
+        p.param_at_length(2.0 * s.eval(2.0)),
+        2.0,
+        1.0e-12
+    ));
+}
+
+#[test]
+fn params_by_length_pythagorean_hodograph() {
+    let (p, s) = pythagorean_hodograph();
+    let total = s.eval(2.0);
+
+    let params = p.params_by_length(11);
+    assert_eq!(params.len(), 11);
+
+    let params: Vec<f64> = params.collect();
+    assert_eq!(params[0], -1.0);
+    assert_eq!(params[10], 2.0);
+    for (k, u) in params.iter().enumerate() {
+        assert!(equal_with_abs_tol(
+            s.eval(*u),
+            total * k as f64 / 10.0,
+            DBL_TOL
+        ));
+    }
+
+    let points: Vec<Complex64> = p.sample_by_length(11).collect();
+    for (z, u) in points.iter().zip(&params) {
+        assert_eq!(*z, p.eval(*u));
+    }
+}
+
+#[test]
+fn params_by_length_few_points() {
+    let (p, _) = pythagorean_hodograph();
+
+    assert_eq!(p.params_by_length(0).count(), 0);
+    assert_eq!(p.params_by_length(1).collect::<Vec<_>>(), vec![-1.0]);
+    assert_eq!(p.params_by_length(2).collect::<Vec<_>>(), vec![-1.0, 2.0]);
+}
+
+#[test]
+fn arc_length_cubic() {
+    // Cubic curve with a cusp, where the speed vanishes at u = 1/2.
+    let p: Bernstein<Complex64, f64, 4> = Bernstein::new([
+        Complex64::new(0.0, 0.0),
+        Complex64::new(1.0, 1.0),
+        Complex64::new(0.0, 1.0),
+        Complex64::new(1.0, 0.0),
+    ]);
+
+    // Length of a fine polygon inscribed into the curve.
+    let n = 200_001;
+    let points: Vec<Complex64> = p.sample(n).collect();
+    let polygon: f64 = points.windows(2).map(|z| (z[1] - z[0]).norm()).sum();
+
+    let total = p.arc_length(0.0, 1.0);
+    assert!(equal_with_abs_tol(total, polygon, 1.0e-9));
+
+    // Evenly spaced points along the curve split it into equal pieces.
+    let params: Vec<f64> = p.params_by_length(9).collect();
+    for u in params.windows(2) {
+        assert!(equal_with_abs_tol(
+            p.arc_length(u[0], u[1]),
+            total / 8.0,
+            1.0e-12
+        ));
+    }
+}
+
+#[test]
+fn arc_length_degenerate() {
+    let (p, _) = pythagorean_hodograph();
+
+    assert_eq!(p.arc_length(0.5, 0.5), 0.0);
+    assert!(p.arc_length(f64::NAN, 1.0).is_nan());
+    assert!(p.arc_length(0.0, f64::INFINITY).is_nan());
+}
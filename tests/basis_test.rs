@@ -0,0 +1,166 @@
+use num::Rational64;
+
+use bernstein::Bernstein;
+
+mod routines;
+pub use routines::*;
+
+#[test]
+fn from_monomial_second_order_f64() {
+    const DBL_TOL: f64 = 1.0e-15;
+    // 1 - 2u + 3u^2
+    let c: Bernstein<f64, f64, 3> = Bernstein::from_monomial([1.0, -2.0, 3.0]);
+    let coef = *c.coef();
+    assert!(equal_with_abs_tol(coef[0], 1.0, DBL_TOL));
+    assert!(equal_with_abs_tol(coef[1], 0.0, DBL_TOL));
+    assert!(equal_with_abs_tol(coef[2], 2.0, DBL_TOL));
+
+    let a = c.to_monomial();
+    assert!(equal_with_abs_tol(a[0], 1.0, DBL_TOL));
+    assert!(equal_with_abs_tol(a[1], -2.0, DBL_TOL));
+    assert!(equal_with_abs_tol(a[2], 3.0, DBL_TOL));
+}
+
+#[test]
+fn monomial_round_trip_rational() {
+    let a = [
+        Rational64::new(1, 5),
+        Rational64::new(-3, 7),
+        Rational64::new(4, 13),
+        Rational64::new(-11, 17),
+        Rational64::new(2, 3),
+    ];
+    let c: Bernstein<Rational64, Rational64, 5> = Bernstein::from_monomial(a);
+    assert_eq!(c.to_monomial(), a);
+
+    for k in 0..=9 {
+        let u = Rational64::new(k, 9);
+        let mut p = Rational64::new(0, 1);
+        for j in (0..5).rev() {
+            p = p * u + a[j];
+        }
+        assert_eq!(c.eval(u), p);
+    }
+
+    let b = Bernstein::<Rational64, Rational64, 5>::from_monomial(c.to_monomial());
+    assert_eq!(b.coef().clone(), c.coef().clone());
+}
+
+// Monomial coefficients are in terms of the actual parameter of the
+// polynomial over an arbitrary interval.
+#[test]
+fn monomial_with_interval_rational() {
+    let a = [
+        Rational64::new(1, 5),
+        Rational64::new(-3, 7),
+        Rational64::new(4, 13),
+        Rational64::new(-11, 17),
+    ];
+    let lo = Rational64::new(-3, 2);
+    let hi = Rational64::new(7, 3);
+    let c: Bernstein<Rational64, Rational64, 4> = Bernstein::from_monomial(a).restrict(lo, hi);
+    assert_eq!(c.to_monomial(), a);
+
+    let c: Bernstein<Rational64, Rational64, 4> = Bernstein::with_interval(a, lo, hi);
+    let m = c.to_monomial();
+    for k in 0..=9 {
+        let u = lo + (hi - lo) * Rational64::new(k, 9);
+        let mut p = Rational64::new(0, 1);
+        for j in (0..4).rev() {
+            p = p * u + m[j];
+        }
+        assert_eq!(c.eval(u), p);
+    }
+}
+
+// Evaluate Chebyshev and Legendre series at `x` in (-1, 1) using three-term
+// recurrences.
+fn chebyshev_series(c: &[Rational64], x: Rational64) -> Rational64 {
+    let (mut t0, mut t1) = (Rational64::new(1, 1), x);
+    let mut p = c[0] * t0;
+    for ck in &c[1..] {
+        p += ck * t1;
+        (t0, t1) = (t1, Rational64::new(2, 1) * x * t1 - t0);
+    }
+    p
+}
+
+fn legendre_series(c: &[Rational64], x: Rational64) -> Rational64 {
+    let (mut p0, mut p1) = (Rational64::new(1, 1), x);
+    let mut p = c[0] * p0;
+    for (k, ck) in c.iter().enumerate().skip(1) {
+        p += ck * p1;
+        let m = k as i64;
+        (p0, p1) = (
+            p1,
+            (Rational64::from(2 * m + 1) * x * p1 - Rational64::from(m) * p0)
+                / Rational64::from(m + 1),
+        );
+    }
+    p
+}
+
+#[test]
+fn chebyshev_round_trip_rational() {
+    let c = [
+        Rational64::new(1, 5),
+        Rational64::new(-3, 7),
+        Rational64::new(4, 13),
+        Rational64::new(-11, 17),
+        Rational64::new(2, 3),
+    ];
+    let b: Bernstein<Rational64, Rational64, 5> = Bernstein::from_chebyshev(c);
+    assert_eq!(b.to_chebyshev(), c);
+
+    for k in 0..=8 {
+        let u = Rational64::new(k, 8);
+        let x = Rational64::new(2, 1) * u - Rational64::new(1, 1);
+        assert_eq!(b.eval(u), chebyshev_series(&c, x));
+    }
+
+    // Chebyshev coefficients do not depend on the interval.
+    let lo = Rational64::new(-1, 2);
+    let hi = Rational64::new(4, 1);
+    assert_eq!(b.rescale(lo, hi).to_chebyshev(), c);
+    let r = b.rescale(lo, hi);
+    assert_eq!(
+        r.eval(Rational64::new(1, 1)),
+        chebyshev_series(&c, Rational64::new(-1, 3))
+    );
+}
+
+#[test]
+fn legendre_round_trip_rational() {
+    let c = [
+        Rational64::new(1, 5),
+        Rational64::new(-3, 7),
+        Rational64::new(4, 13),
+        Rational64::new(-11, 17),
+        Rational64::new(2, 3),
+        Rational64::new(1, 2),
+    ];
+    let b: Bernstein<Rational64, Rational64, 6> = Bernstein::from_legendre(c);
+    assert_eq!(b.to_legendre(), c);
+
+    for k in 0..=8 {
+        let u = Rational64::new(k, 8);
+        let x = Rational64::new(2, 1) * u - Rational64::new(1, 1);
+        assert_eq!(b.eval(u), legendre_series(&c, x));
+    }
+}
+
+#[test]
+fn orthogonal_bases_f64() {
+    const DBL_TOL: f64 = 1.0e-14;
+    let c: Bernstein<f64, f64, 4> = Bernstein::new([1.0, -2.0, 0.5, 3.0]);
+
+    let b: Bernstein<f64, f64, 4> = Bernstein::from_chebyshev(c.to_chebyshev());
+    for i in 0..4 {
+        assert!(equal_with_abs_tol(b.coef()[i], c.coef()[i], DBL_TOL));
+    }
+
+    let b: Bernstein<f64, f64, 4> = Bernstein::from_legendre(c.to_legendre());
+    for i in 0..4 {
+        assert!(equal_with_abs_tol(b.coef()[i], c.coef()[i], DBL_TOL));
+    }
+}
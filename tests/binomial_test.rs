@@ -0,0 +1,70 @@
+use num::Rational64;
+
+use bernstein::{binomial, binomial_as, binomial_row, pascal, Bernstein};
+
+mod routines;
+pub use routines::*;
+
+const PASCAL: [[usize; 6]; 6] = pascal::<6>();
+
+#[test]
+fn pascal_compile_time() {
+    assert_eq!(PASCAL[0], [1, 0, 0, 0, 0, 0]);
+    assert_eq!(PASCAL[5], [1, 5, 10, 10, 5, 1]);
+
+    let table = pascal::<20>();
+    for (n, row) in table.iter().enumerate() {
+        for (k, c) in row.iter().enumerate() {
+            assert_eq!(Some(*c), binomial(n, k));
+        }
+    }
+}
+
+#[test]
+fn binomial_as_large_degree() {
+    assert_eq!(binomial(100, 50), None);
+
+    // Row sums are powers of two also beyond the range of `usize`.
+    let sum: f64 = (0..=100).map(|k| binomial_as::<f64>(100, k)).sum();
+    assert!(equal_with_abs_tol(sum / 2.0f64.powi(100), 1.0, 1.0e-14));
+
+    let row: [f64; 101] = binomial_row();
+    for (k, c) in row.iter().enumerate() {
+        assert_eq!(*c, row[100 - k]);
+        assert!(equal_with_abs_tol(
+            c / binomial_as::<f64>(100, k),
+            1.0,
+            1.0e-14
+        ));
+    }
+}
+
+#[test]
+fn binomial_row_rational() {
+    let row: [Rational64; 8] = binomial_row();
+    for (k, c) in row.iter().enumerate() {
+        assert_eq!(*c, Rational64::from_integer(binomial(7, k).unwrap() as i64));
+    }
+    assert_eq!(
+        binomial_as::<Rational64>(7, 3),
+        Rational64::from_integer(35)
+    );
+}
+
+// Product of the polynomials of high degrees does not overflow the
+// binomial coefficients.
+#[test]
+fn mul_high_degree_f64() {
+    const DBL_TOL: f64 = 1.0e-12;
+    let p: Bernstein<f64, f64, 40> = Bernstein::new([1.0; 40]);
+
+    let c = p.mul_into::<40, 79>(&p);
+    for x in c.coef() {
+        assert!(equal_with_abs_tol(*x, 1.0, DBL_TOL));
+    }
+
+    let e = p.elevate_to::<100>();
+    for x in e.coef() {
+        assert!(equal_with_abs_tol(*x, 1.0, DBL_TOL));
+    }
+}
@@ -0,0 +1,94 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+use bernstein::Bernstein;
+
+mod routines;
+pub use routines::*;
+
+// Blossom is symmetric, multi-affine, and is the polynomial on the diagonal.
+#[test]
+#[cfg(feature = "nightly")]
+fn blossom_properties_rational() {
+    let p = cubic();
+    let (x, y, z) = (r(1, 3), r(-2, 5), r(7, 4));
+
+    let b = p.blossom(&[x, y, z]);
+    assert_eq!(p.blossom(&[y, x, z]), b);
+    assert_eq!(p.blossom(&[z, y, x]), b);
+    assert_eq!(p.blossom(&[y, z, x]), b);
+
+    let t = r(2, 7);
+    let w = r(5, 6);
+    assert_eq!(
+        p.blossom(&[x * (r(1, 1) - t) + w * t, y, z]),
+        p.blossom(&[x, y, z]) * (r(1, 1) - t) + p.blossom(&[w, y, z]) * t
+    );
+
+    for k in 0..=6 {
+        let u = r(-1, 1) + r(k, 2);
+        assert_eq!(p.blossom(&[u, u, u]), p.eval(u));
+    }
+}
+
+// Coefficients over a sub-interval are blossoms at its end points.
+#[test]
+fn blossom_split_restrict_rational() {
+    let p = cubic();
+    let (a, b) = p.interval();
+    let u = r(1, 3);
+
+    let (left, right) = p.split(u);
+    for i in 0..4 {
+        let args: Vec<_> = (0..3).map(|k| if k < 3 - i { a } else { u }).collect();
+        assert_eq!(left.coef()[i], p.blossom_slice(&args));
+
+        let args: Vec<_> = (0..3).map(|k| if k < 3 - i { u } else { b }).collect();
+        assert_eq!(right.coef()[i], p.blossom_slice(&args));
+    }
+
+    let (c, d) = (r(-1, 2), r(3, 2));
+    let q = p.restrict(c, d);
+    for i in 0..4 {
+        let args: Vec<_> = (0..3).map(|k| if k < 3 - i { c } else { d }).collect();
+        assert_eq!(q.coef()[i], p.blossom_slice(&args));
+    }
+}
+
+// Blossom of the elevated polynomial is the average of the blossoms of the
+// original polynomial with one argument dropped.
+#[test]
+fn blossom_elevation_rational() {
+    let p = cubic();
+    let e = p.elevate_to::<5>();
+    let args = [r(1, 3), r(-2, 5), r(7, 4), r(0, 1)];
+
+    let mut avg = r(0, 1);
+    for j in 0..4 {
+        let rest: Vec<_> = (0..4).filter(|&k| k != j).map(|k| args[k]).collect();
+        avg += p.blossom_slice(&rest) / r(4, 1);
+    }
+    assert_eq!(e.blossom_slice(&args), avg);
+}
+
+// Blossom of the derivative is the difference of the blossoms with the end
+// points of the interval as the last argument.
+#[test]
+fn blossom_diff_rational() {
+    let p = cubic();
+    let (a, b) = p.interval();
+    let d = p.diff_into::<3>();
+    let (x, y) = (r(1, 3), r(-2, 5));
+
+    assert_eq!(
+        d.blossom_slice(&[x, y]),
+        (p.blossom_slice(&[x, y, b]) - p.blossom_slice(&[x, y, a])) * r(3, 1) / (b - a)
+    );
+}
+
+#[test]
+#[should_panic]
+fn blossom_slice_wrong_size() {
+    let p: Bernstein<f64, f64, 3> = Bernstein::new([1.0, 2.0, 3.0]);
+    let _ = p.blossom_slice(&[0.5]);
+}
@@ -0,0 +1,88 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+use num::Complex;
+
+use bernstein::Bernstein;
+
+mod routines;
+pub use routines::*;
+
+#[test]
+fn bounds_scalar_f64() {
+    const DBL_TOL: f64 = 1.0e-15;
+    // Maximum of the parabola is 1 at u = 0.5.
+    let c: Bernstein<f64, f64, 3> = Bernstein::new([0.0, 2.0, 0.0]);
+
+    let (lo, hi) = c.control_polygon_bounds();
+    assert_eq!((lo, hi), (0.0, 2.0));
+
+    let (lo, hi) = c.bounding_box();
+    assert!(equal_with_abs_tol(lo, 0.0, DBL_TOL));
+    assert!(equal_with_abs_tol(hi, 1.0, DBL_TOL));
+}
+
+#[test]
+fn bounds_quadratic_complex() {
+    const DBL_TOL: f64 = 1.0e-15;
+    let c: Bernstein<Complex<f64>, f64, 3> = Bernstein::new([
+        Complex::new(0.0, 0.0),
+        Complex::new(1.0, 2.0),
+        Complex::new(2.0, 0.0),
+    ]);
+
+    let (lo, hi) = c.control_polygon_bounds();
+    assert_eq!(lo, Complex::new(0.0, 0.0));
+    assert_eq!(hi, Complex::new(2.0, 2.0));
+
+    let (lo, hi) = c.bounding_box();
+    assert!(equal_with_abs_tol(
+        (lo - Complex::new(0.0, 0.0)).norm(),
+        0.0,
+        DBL_TOL
+    ));
+    assert!(equal_with_abs_tol(
+        (hi - Complex::new(2.0, 1.0)).norm(),
+        0.0,
+        DBL_TOL
+    ));
+}
+
+#[test]
+fn bounds_cubic_complex() {
+    const DBL_TOL: f64 = 1.0e-14;
+    let c: Bernstein<Complex<f64>, f64, 4> = Bernstein::new([
+        Complex::new(0.0, 0.0),
+        Complex::new(2.5, 1.0),
+        Complex::new(-0.5, 1.0),
+        Complex::new(2.0, 0.0),
+    ]);
+
+    let (lo, hi) = c.control_polygon_bounds();
+    assert_eq!(lo, Complex::new(-0.5, 0.0));
+    assert_eq!(hi, Complex::new(2.5, 1.0));
+
+    // Compare with the dense sampling of the curve.
+    let (lo, hi) = c.bounding_box();
+    let (mut re, mut im) = ((f64::MAX, f64::MIN), (f64::MAX, f64::MIN));
+    for k in 0..=100000 {
+        let p = c.eval(k as f64 / 100000.0);
+        re = (re.0.min(p.re), re.1.max(p.re));
+        im = (im.0.min(p.im), im.1.max(p.im));
+    }
+    assert!(equal_with_abs_tol(lo.re, re.0, 1.0e-9));
+    assert!(equal_with_abs_tol(hi.re, re.1, 1.0e-9));
+    assert!(equal_with_abs_tol(lo.im, 0.0, DBL_TOL));
+    assert!(equal_with_abs_tol(hi.im, 0.75, DBL_TOL));
+    assert!(lo.re <= re.0 && hi.re >= re.1);
+}
+
+#[test]
+fn bounds_with_interval_f64() {
+    const DBL_TOL: f64 = 1.0e-14;
+    let c: Bernstein<f64, f64, 3> = Bernstein::with_interval([1.0, -1.0, 2.0], 2.0, 4.0);
+    // Minimum of `4 u^2 - 4 u + 1` over (0, 1) is at u = 0.4.
+    let (lo, hi) = c.bounding_box();
+    assert!(equal_with_abs_tol(lo, c.eval(2.8), DBL_TOL));
+    assert!(equal_with_abs_tol(hi, 2.0, DBL_TOL));
+}
@@ -0,0 +1,167 @@
+use num::complex::Complex64;
+use num::Rational64;
+
+use bernstein::{BSpline, Continuity};
+
+mod routines;
+pub use routines::*;
+
+fn clamped_cubic() -> BSpline<Rational64, Rational64, 4> {
+    BSpline::new(
+        vec![r(1, 5), r(-3, 7), r(4, 13), r(-11, 17), r(2, 3), r(1, 2)],
+        vec![
+            r(0, 1),
+            r(0, 1),
+            r(0, 1),
+            r(0, 1),
+            r(1, 3),
+            r(1, 2),
+            r(1, 1),
+            r(1, 1),
+            r(1, 1),
+            r(1, 1),
+        ],
+    )
+}
+
+#[test]
+fn bspline_eval_end_points_rational() {
+    let c = clamped_cubic();
+    assert_eq!(c.interval(), (r(0, 1), r(1, 1)));
+    assert_eq!(c.eval(r(0, 1)), r(1, 5));
+    assert_eq!(c.eval(r(1, 1)), r(1, 2));
+}
+
+// Single segment with the clamped knot vector is a Bezier curve.
+#[test]
+fn bspline_single_segment_f64() {
+    const DBL_TOL: f64 = 1.0e-15;
+    let c: BSpline<f64, f64, 3> =
+        BSpline::new(vec![1.0, 2.0, -1.0], vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+    assert!(equal_with_abs_tol(c.eval(0.5), 1.0, DBL_TOL));
+
+    let b = c.to_bezier();
+    assert_eq!(b.segments().len(), 1);
+    assert_eq!(*b.segments()[0].coef(), [1.0, 2.0, -1.0]);
+}
+
+#[test]
+fn bspline_to_bezier_rational() {
+    let c = clamped_cubic();
+    let b = c.to_bezier();
+
+    assert_eq!(b.segments().len(), 3);
+    assert_eq!(b.segments()[0].interval(), (r(0, 1), r(1, 3)));
+    assert_eq!(b.segments()[1].interval(), (r(1, 3), r(1, 2)));
+    assert_eq!(b.segments()[2].interval(), (r(1, 2), r(1, 1)));
+    assert!(b.is_continuous(Continuity::C2, r(0, 1)));
+
+    for k in 0..=24 {
+        let u = r(k, 24);
+        assert_eq!(b.eval(u), c.eval(u));
+    }
+}
+
+#[test]
+fn bspline_insert_knot_rational() {
+    let c = clamped_cubic();
+
+    for u in [r(1, 7), r(1, 3), r(1, 2), r(4, 5)] {
+        let mut d = c.clone();
+        d.insert_knot(u);
+        assert_eq!(d.coef().len(), c.coef().len() + 1);
+        assert_eq!(d.knots().len(), c.knots().len() + 1);
+        assert!(d.knots().contains(&u));
+
+        for k in 0..=24 {
+            let v = r(k, 24);
+            assert_eq!(d.eval(v), c.eval(v));
+        }
+    }
+}
+
+// Knots at the ends of the clamped knot vector already have the multiplicity
+// of the order of the curve.
+#[test]
+#[should_panic(expected = "multiplicity of the knot exceeds the order of the curve")]
+fn bspline_insert_knot_clamped_end() {
+    let mut c = clamped_cubic();
+    c.insert_knot(r(1, 1));
+}
+
+// Unclamped uniform knot vector.
+#[test]
+fn bspline_uniform_rational() {
+    let c: BSpline<Rational64, Rational64, 3> = BSpline::new(
+        vec![r(0, 1), r(1, 1), r(3, 1), r(2, 1), r(-1, 1)],
+        (0..8).map(|k| r(k, 1)).collect(),
+    );
+    assert_eq!(c.interval(), (r(2, 1), r(5, 1)));
+
+    // Uniform quadratic B-spline at the knot is the average of the points.
+    assert_eq!(c.eval(r(3, 1)), r(2, 1));
+
+    let b = c.to_bezier();
+    assert_eq!(b.segments().len(), 3);
+    assert_eq!(*b.segments()[0].coef(), [r(1, 2), r(1, 1), r(2, 1)]);
+    for k in 0..=12 {
+        let u = r(2, 1) + r(k, 4);
+        assert_eq!(b.eval(u), c.eval(u));
+    }
+
+    let mut d = c.clone();
+    d.insert_knot(r(5, 1));
+    for k in 0..=12 {
+        let u = r(2, 1) + r(k, 4);
+        assert_eq!(d.eval(u), c.eval(u));
+    }
+}
+
+#[test]
+fn bspline_from_bezier_rational() {
+    let c = clamped_cubic();
+    let b = c.to_bezier();
+
+    // Recover the original knots and control points from the C^2 chain.
+    let d = BSpline::from_bezier(&b, 2);
+    assert_eq!(d.knots(), c.knots());
+    assert_eq!(d.coef(), c.coef());
+
+    // Lower continuity introduces knots of higher multiplicity.
+    let d = BSpline::from_bezier(&b, 0);
+    assert_eq!(d.knots().len(), 4 + 3 + 3 + 4);
+    assert_eq!(d.coef().len(), 10);
+    for k in 0..=24 {
+        let u = r(k, 24);
+        assert_eq!(d.eval(u), c.eval(u));
+    }
+}
+
+#[test]
+fn bspline_complex_f64() {
+    const DBL_TOL: f64 = 1.0e-14;
+    let c: BSpline<Complex64, f64, 4> = BSpline::new(
+        vec![
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 2.0),
+            Complex64::new(2.0, -1.0),
+            Complex64::new(3.0, 1.0),
+            Complex64::new(4.0, 0.0),
+        ],
+        vec![0.0, 0.0, 0.0, 0.0, 0.4, 1.0, 1.0, 1.0, 1.0],
+    );
+    let b = c.to_bezier();
+    for k in 0..=100 {
+        let u = k as f64 / 100.0;
+        assert!(equal_with_abs_tol(
+            (b.eval(u) - c.eval(u)).norm(),
+            0.0,
+            DBL_TOL
+        ));
+    }
+
+    let d = BSpline::from_bezier(&b, 2);
+    for (p, q) in d.coef().iter().zip(c.coef()) {
+        assert!(equal_with_abs_tol((p - q).norm(), 0.0, DBL_TOL));
+    }
+}
@@ -0,0 +1,86 @@
+use num::{BigRational, ToPrimitive};
+
+use bernstein::Bernstein;
+
+// Exact value of the polynomial over (0, 1) at the floating point parameter.
+fn exact(coef: &[f64], t: f64) -> f64 {
+    let t = BigRational::from_float(t).unwrap();
+    let s = BigRational::from_float(1.0).unwrap() - &t;
+    let mut q: Vec<BigRational> = coef
+        .iter()
+        .map(|c| BigRational::from_float(*c).unwrap())
+        .collect();
+
+    for k in 1..q.len() {
+        for i in 0..q.len() - k {
+            q[i] = &q[i] * &s + &q[i + 1] * &t;
+        }
+    }
+    q[0].to_f64().unwrap()
+}
+
+// Polynomial `(t - r)^n` in the Bernstein basis has coefficients
+// `(1 - r)^i (-r)^(n - i)`, which are exact for `r = 0.75`.
+fn power_coef<const N: usize>(r: f64) -> [f64; N] {
+    std::array::from_fn(|i| (1.0 - r).powi(i as i32) * (-r).powi((N - 1 - i) as i32))
+}
+
+#[test]
+fn compensated_near_multiple_root_f64() {
+    let coef: [f64; 8] = power_coef(0.75);
+    let p: Bernstein<f64, f64, 8> = Bernstein::new(coef);
+
+    // Error bound holds even at the points where the compensated value has
+    // no significant digits.
+    for k in 0..=400 {
+        let t = 0.74 + 0.02 * k as f64 / 400.0;
+        let (v, bound) = p.eval_compensated(t);
+        assert!((v - exact(&coef, t)).abs() <= bound);
+    }
+
+    let mut worst_plain: f64 = 0.0;
+    let mut worst_comp: f64 = 0.0;
+
+    for k in 0..=100 {
+        for t in [0.749 - 1.0e-5 * k as f64, 0.751 + 1.0e-5 * k as f64] {
+            let e = exact(&coef, t);
+            let (v, _) = p.eval_compensated(t);
+            worst_plain = worst_plain.max(((p.eval(t) - e) / e).abs());
+            worst_comp = worst_comp.max(((v - e) / e).abs());
+        }
+    }
+
+    // Plain evaluation loses most significant digits, while the compensated
+    // one is as accurate as in the doubled precision.
+    assert!(worst_plain > 1.0e-1);
+    assert!(worst_comp < 1.0e-13);
+}
+
+#[test]
+fn compensated_well_conditioned_f64() {
+    let coef = [0.5, -1.5, 2.0, 1.0, 0.25];
+    let p: Bernstein<f64, f64, 5> = Bernstein::new(coef);
+
+    for k in 0..=100 {
+        let t = k as f64 / 100.0;
+        let (v, bound) = p.eval_compensated(t);
+        let e = exact(&coef, t);
+        assert!((v - e).abs() <= bound);
+        assert!(bound <= 4.0 * f64::EPSILON * e.abs().max(1.0));
+    }
+}
+
+#[test]
+fn compensated_f32() {
+    let coef: [f32; 6] =
+        std::array::from_fn(|i| 0.25f32.powi(i as i32) * (-0.75f32).powi(5 - i as i32));
+    let p: Bernstein<f32, f32, 6> = Bernstein::new(coef);
+
+    let coef64: Vec<f64> = coef.iter().map(|c| *c as f64).collect();
+    for k in 0..=100 {
+        let t = 0.7 + 0.1 * k as f32 / 100.0;
+        let (v, bound) = p.eval_compensated(t);
+        let e = exact(&coef64, t as f64);
+        assert!((v as f64 - e).abs() <= bound as f64);
+    }
+}
@@ -0,0 +1,84 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+use num::complex::Complex64;
+use num::Rational64;
+
+use bernstein::Bernstein;
+
+mod routines;
+pub use routines::*;
+
+#[test]
+#[cfg(feature = "nightly")]
+fn compose_cubic_quadratic_rational() {
+    let p = cubic();
+    let q: Bernstein<Rational64, Rational64, 3> =
+        Bernstein::with_interval([r(-1, 1), r(3, 1), r(1, 2)], r(1, 1), r(4, 1));
+
+    let c = p.compose(&q);
+    assert_eq!(c.coef().len(), 7);
+    assert_eq!(c.interval(), (r(1, 1), r(4, 1)));
+
+    for k in 0..=12 {
+        let t = r(1, 1) + r(k, 4);
+        assert_eq!(c.eval(t), p.eval(q.eval(t)));
+    }
+}
+
+#[test]
+fn compose_into_complex_f64() {
+    const DBL_TOL: f64 = 1.0e-14;
+    let p: Bernstein<Complex64, f64, 3> = Bernstein::new([
+        Complex64::new(0.0, 0.0),
+        Complex64::new(1.0, 2.0),
+        Complex64::new(3.0, -1.0),
+    ]);
+    // Time warping that maps (0, 1) onto itself.
+    let q: Bernstein<f64, f64, 4> = Bernstein::new([0.0, 0.6, 0.1, 1.0]);
+
+    let c = p.compose_into::<4, 7>(&q);
+    assert!(equal_with_abs_tol(
+        (c.eval(0.0) - p.eval(0.0)).norm(),
+        0.0,
+        DBL_TOL
+    ));
+    assert!(equal_with_abs_tol(
+        (c.eval(1.0) - p.eval(1.0)).norm(),
+        0.0,
+        DBL_TOL
+    ));
+
+    for k in 0..=20 {
+        let t = k as f64 / 20.0;
+        assert!(equal_with_abs_tol(
+            (c.eval(t) - p.eval(q.eval(t))).norm(),
+            0.0,
+            DBL_TOL
+        ));
+    }
+}
+
+// Composition with a linear polynomial is a reparameterization.
+#[test]
+fn compose_into_linear_rational() {
+    let p: Bernstein<Rational64, Rational64, 4> =
+        Bernstein::new([r(1, 2), r(-3, 7), r(5, 3), r(2, 1)]);
+    let q: Bernstein<Rational64, Rational64, 2> = Bernstein::new([r(1, 4), r(2, 3)]);
+
+    let c = p.compose_into::<2, 4>(&q);
+    assert_eq!(*c.coef(), *p.restrict(r(1, 4), r(2, 3)).coef());
+
+    // Identity map does not change the polynomial.
+    let q: Bernstein<Rational64, Rational64, 3> = Bernstein::new([r(0, 1), r(1, 2), r(1, 1)]);
+    let c = p.compose_into::<3, 7>(&q);
+    assert_eq!(*c.coef(), *p.elevate_to::<7>().coef());
+}
+
+#[test]
+#[should_panic]
+fn compose_into_wrong_size() {
+    let p: Bernstein<f64, f64, 3> = Bernstein::new([1.0, 2.0, 3.0]);
+    let q: Bernstein<f64, f64, 3> = Bernstein::new([0.0, 0.5, 1.0]);
+    let _ = p.compose_into::<3, 4>(&q);
+}
@@ -0,0 +1,100 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+use bernstein::{Bernstein, DynBernstein};
+
+use num::complex::Complex64;
+use num::rational::Rational64;
+
+mod routines;
+pub use routines::*;
+
+#[test]
+fn dynamic_conversions() {
+    let p: Bernstein<i32, i32, 3> = Bernstein::with_interval([1, 2, 3], -1, 2);
+
+    let d = DynBernstein::from(p);
+    assert_eq!(d.coef(), &[1, 2, 3]);
+    assert_eq!(d.interval(), (-1, 2));
+
+    let q: Bernstein<i32, i32, 3> = d.clone().try_into().unwrap();
+    assert_eq!(*q.coef(), [1, 2, 3]);
+    assert_eq!(q.interval(), (-1, 2));
+
+    // Wrong size returns the original polynomial back.
+    let e: Result<Bernstein<i32, i32, 4>, _> = d.clone().try_into();
+    assert_eq!(e.unwrap_err(), d);
+}
+
+#[test]
+fn dynamic_eval_diff_integ_rational() {
+    let p: Bernstein<Rational64, Rational64, 4> =
+        Bernstein::with_interval([r(1, 2), r(-3, 7), r(5, 3), r(2, 1)], r(-1, 3), r(2, 1));
+    let d = DynBernstein::from(p);
+
+    for k in 0..=12 {
+        let u = r(-1, 3) + r(k, 12) * r(7, 3);
+        assert_eq!(d.eval(u), p.eval(u));
+    }
+
+    assert_eq!(d.diff(), DynBernstein::from(p.diff_into::<3>()));
+    assert_eq!(
+        d.diff().diff(),
+        DynBernstein::from(p.diff_into::<3>().diff_into::<2>())
+    );
+    assert_eq!(
+        d.integ(r(3, 5)),
+        DynBernstein::from(p.integ_into::<5>(r(3, 5)))
+    );
+    assert_eq!(d.integ(r(3, 5)).diff(), d);
+}
+
+#[test]
+fn dynamic_integ_empty_rational() {
+    let d: DynBernstein<Rational64, Rational64> =
+        DynBernstein::with_interval(vec![], r(1, 1), r(3, 1));
+    assert_eq!(
+        d.integ(r(3, 5)),
+        DynBernstein::with_interval(vec![r(3, 5)], r(1, 1), r(3, 1))
+    );
+    assert_eq!(d.integ(r(3, 5)).diff(), d);
+}
+
+#[test]
+fn dynamic_product_rational() {
+    let p: Bernstein<Rational64, Rational64, 3> =
+        Bernstein::with_interval([r(1, 2), r(-3, 7), r(5, 3)], r(1, 1), r(3, 1));
+    let q: Bernstein<Rational64, Rational64, 4> =
+        Bernstein::with_interval([r(2, 1), r(1, 9), r(0, 1), r(-4, 5)], r(1, 1), r(3, 1));
+
+    let c = DynBernstein::from(p) * DynBernstein::from(q);
+    assert_eq!(c.coef().len(), 6);
+    assert_eq!(c, DynBernstein::from(p.mul_into::<4, 6>(&q)));
+}
+
+#[test]
+fn dynamic_product_complex() {
+    const DBL_TOL: f64 = 1.0e-14;
+    let w: DynBernstein<Complex64, f64> = DynBernstein::new(vec![
+        Complex64::new(1.0, 0.0),
+        Complex64::new(0.0, 0.0),
+        Complex64::new(0.0, 1.0),
+    ]);
+
+    let p = (w.clone() * w.clone()).integ(Complex64::new(-1.0, 0.0));
+    assert_eq!(p.coef().len(), 6);
+
+    for k in 0..=10 {
+        let u = k as f64 / 10.0;
+        let z = p.diff().eval(u) - w.eval(u) * w.eval(u);
+        assert!(equal_with_abs_tol(z.norm(), 0.0, DBL_TOL));
+    }
+}
+
+#[test]
+#[should_panic(expected = "polynomials are defined over different intervals")]
+fn dynamic_product_different_intervals() {
+    let p: DynBernstein<f64, f64> = DynBernstein::new(vec![1.0, 2.0]);
+    let q: DynBernstein<f64, f64> = DynBernstein::with_interval(vec![1.0, 2.0], 0.0, 2.0);
+    let _ = p * q;
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "nightly")]
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+use num::{Rational64, Zero};
+
+use bernstein::Bernstein;
+
+mod routines;
+pub use routines::*;
+
+#[test]
+fn elevate_first_order_f64() {
+    const DBL_TOL: f64 = 1.0e-15;
+    let c: Bernstein<f64, f64, 2> = Bernstein::new([1.0, 3.0]);
+    let coef = *c.elevate().coef();
+    assert!(equal_with_abs_tol(coef[0], 1.0, DBL_TOL));
+    assert!(equal_with_abs_tol(coef[1], 2.0, DBL_TOL));
+    assert!(equal_with_abs_tol(coef[2], 3.0, DBL_TOL));
+}
+
+#[test]
+fn elevate_third_order_rational() {
+    let p0 = Rational64::new(1, 5);
+    let p1 = Rational64::new(-3, 7);
+    let p2 = Rational64::new(4, 13);
+    let p3 = Rational64::new(-11, 17);
+
+    let c: Bernstein<Rational64, Rational64, 4> = Bernstein::new([p0, p1, p2, p3]);
+    let e = c.elevate();
+
+    // End points are preserved.
+    assert_eq!(e.coef()[0], p0);
+    assert_eq!(e.coef()[4], p3);
+    // Interior points are convex combinations of the original ones.
+    assert_eq!(
+        e.coef()[1],
+        Rational64::new(1, 4) * p0 + Rational64::new(3, 4) * p1
+    );
+    assert_eq!(
+        e.coef()[2],
+        Rational64::new(1, 2) * p1 + Rational64::new(1, 2) * p2
+    );
+    assert_eq!(
+        e.coef()[3],
+        Rational64::new(3, 4) * p2 + Rational64::new(1, 4) * p3
+    );
+
+    for k in 0..=17 {
+        let u = Rational64::new(k, 17);
+        assert_eq!(e.eval(u), c.eval(u));
+    }
+}
+
+// Elevation by several degrees at once is the same as repeated elevation.
+#[test]
+fn elevate_to_rational() {
+    let coef = [
+        Rational64::new(1, 13),
+        Rational64::new(-3, 11),
+        Rational64::new(1, 7),
+    ];
+    let c: Bernstein<Rational64, Rational64, 3> = Bernstein::new(coef);
+
+    let e: Bernstein<Rational64, Rational64, 6> = c.elevate_to();
+    assert_eq!(
+        e.coef().clone(),
+        c.elevate().elevate().elevate().coef().clone()
+    );
+
+    for k in 0..=11 {
+        let u = Rational64::new(k, 11);
+        assert_eq!(e.eval(u), c.eval(u));
+    }
+
+    let e: Bernstein<Rational64, Rational64, 3> = c.elevate_to();
+    assert_eq!(e.coef().clone(), coef);
+}
+
+// Derivative of the elevated polynomial is the elevated derivative.
+#[test]
+fn elevate_diff_commute_rational() {
+    let c: Bernstein<Rational64, Rational64, 4> = Bernstein::new([
+        Rational64::new(2, 3),
+        Rational64::zero(),
+        Rational64::new(-5, 7),
+        Rational64::new(1, 2),
+    ]);
+    assert_eq!(
+        c.elevate().diff().coef().clone(),
+        c.diff().elevate().coef().clone()
+    );
+}
+
+#[test]
+#[should_panic]
+fn elevate_to_smaller_size() {
+    let c: Bernstein<f64, f64, 3> = Bernstein::new([1.0, 2.0, 3.0]);
+    let _: Bernstein<f64, f64, 2> = c.elevate_to();
+}
@@ -0,0 +1,85 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+use num::Rational64;
+
+use bernstein::{Bernstein, Error};
+
+mod routines;
+pub use routines::*;
+
+#[test]
+fn try_diff_integ_ok_rational() {
+    let p: Bernstein<Rational64, Rational64, 3> =
+        Bernstein::with_interval([r(1, 2), r(-3, 7), r(5, 3)], r(1, 1), r(3, 1));
+
+    let d = p.try_diff_into::<2>().unwrap();
+    assert_eq!(*d.coef(), *p.diff_into::<2>().coef());
+
+    let i = p.try_integ_into::<4>(r(1, 1)).unwrap();
+    assert_eq!(*i.coef(), *p.integ_into::<4>(r(1, 1)).coef());
+}
+
+// Degree of the polynomial cannot be represented by `u8`.
+#[test]
+fn try_diff_integ_conversion() {
+    let p: Bernstein<u8, u8, 300> = Bernstein::new([1; 300]);
+
+    assert_eq!(p.try_diff_into::<299>().unwrap_err(), Error::Conversion);
+    assert_eq!(p.try_integ_into::<301>(0).unwrap_err(), Error::Conversion);
+}
+
+#[test]
+fn checked_mul_errors() {
+    let p: Bernstein<f64, f64, 2> = Bernstein::new([1.0, 2.0]);
+    let q: Bernstein<f64, f64, 2> = Bernstein::with_interval([1.0, 2.0], 0.0, 2.0);
+    assert_eq!(
+        p.checked_mul_into::<2, 3>(&q).unwrap_err(),
+        Error::IntervalMismatch
+    );
+
+    let c = p.checked_mul_into::<2, 3>(&p).unwrap();
+    assert_eq!(*c.coef(), *p.mul_into::<2, 3>(&p).coef());
+
+    // Binomial coefficients of the degree 78 overflow 64-bit `usize`, and are
+    // only represented approximately by `f64`.
+    let p: Bernstein<f64, f64, 40> = Bernstein::new([1.0; 40]);
+    let c = p.checked_mul_into::<40, 79>(&p).unwrap();
+    assert_eq!(*c.coef(), *p.mul_into::<40, 79>(&p).coef());
+
+    let p: Bernstein<u64, u64, 40> = Bernstein::new([1; 40]);
+    assert_eq!(
+        p.checked_mul_into::<40, 79>(&p).unwrap_err(),
+        Error::Overflow
+    );
+
+    // Binomial coefficients of the degree 67 fit into 64-bit `usize`, but not
+    // into `i64`.
+    let p: Bernstein<Rational64, Rational64, 34> = Bernstein::new([r(1, 1); 34]);
+    let q: Bernstein<Rational64, Rational64, 35> = Bernstein::new([r(1, 1); 35]);
+    assert_eq!(
+        p.checked_mul_into::<35, 68>(&q).unwrap_err(),
+        Error::Conversion
+    );
+}
+
+#[test]
+#[cfg(feature = "nightly")]
+fn try_inferred_size_rational() {
+    let p: Bernstein<Rational64, Rational64, 3> = Bernstein::new([r(1, 2), r(-3, 7), r(5, 3)]);
+
+    assert_eq!(*p.try_diff().unwrap().coef(), *p.diff().coef());
+    assert_eq!(
+        *p.try_integ(r(1, 1)).unwrap().coef(),
+        *p.integ(r(1, 1)).coef()
+    );
+    assert_eq!(*p.checked_mul(&p).unwrap().coef(), *(p * p).coef());
+}
+
+#[test]
+fn error_display() {
+    assert_eq!(
+        Error::IntervalMismatch.to_string(),
+        "polynomials are defined over different intervals"
+    );
+}
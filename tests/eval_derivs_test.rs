@@ -0,0 +1,87 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+use num::complex::Complex64;
+#[cfg(feature = "nightly")]
+use num::Rational64;
+
+use bernstein::Bernstein;
+
+mod routines;
+pub use routines::*;
+
+#[test]
+fn eval_derivs_cubic_rational() {
+    let p = cubic();
+    let d1 = p.diff_into::<3>();
+    let d2 = d1.diff_into::<2>();
+    let d3 = d2.diff_into::<1>();
+
+    for k in 0..=6 {
+        let u = r(-1, 1) + r(k, 2);
+        let d = p.eval_derivs_into::<6>(u);
+        assert_eq!(
+            d,
+            [
+                p.eval(u),
+                d1.eval(u),
+                d2.eval(u),
+                d3.eval(u),
+                r(0, 1),
+                r(0, 1)
+            ]
+        );
+        assert_eq!(p.eval_derivs_into::<1>(u), [p.eval(u)]);
+    }
+}
+
+#[test]
+fn eval_derivs_quintic_complex() {
+    const DBL_TOL: f64 = 1.0e-12;
+    let p: Bernstein<Complex64, f64, 6> = Bernstein::with_interval(
+        [
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 2.0),
+            Complex64::new(3.0, -1.0),
+            Complex64::new(4.0, 0.5),
+            Complex64::new(2.0, 2.0),
+            Complex64::new(5.0, 1.0),
+        ],
+        0.5,
+        2.0,
+    );
+
+    for k in 0..=10 {
+        let u = 0.5 + 0.15 * k as f64;
+        let [x, v, a] = p.eval_derivs_into::<3>(u);
+        assert!(equal_with_abs_tol((x - p.eval(u)).norm(), 0.0, DBL_TOL));
+        assert!(equal_with_abs_tol(
+            (v - p.diff_into::<5>().eval(u)).norm(),
+            0.0,
+            DBL_TOL
+        ));
+        assert!(equal_with_abs_tol(
+            (a - p.diff_into::<5>().diff_into::<4>().eval(u)).norm(),
+            0.0,
+            DBL_TOL
+        ));
+    }
+}
+
+#[test]
+#[cfg(feature = "nightly")]
+fn eval_derivs_inferred_size_rational() {
+    let p: Bernstein<Rational64, Rational64, 3> = Bernstein::new([r(1, 2), r(-3, 7), r(5, 3)]);
+    let u = r(2, 5);
+
+    let [x, v, a] = p.eval_derivs::<2>(u);
+    assert_eq!(x, p.eval(u));
+    assert_eq!(v, p.diff().eval(u));
+    assert_eq!(a, p.diff().diff().eval(u));
+}
+
+#[test]
+fn eval_derivs_constant_f64() {
+    let p: Bernstein<f64, f64, 1> = Bernstein::new([3.0]);
+    assert_eq!(p.eval_derivs_into::<3>(0.5), [3.0, 0.0, 0.0]);
+}
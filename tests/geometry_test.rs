@@ -0,0 +1,156 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+use num::complex::Complex64;
+
+use bernstein::{Bernstein, Cross};
+
+mod routines;
+pub use routines::*;
+
+const DBL_TOL: f64 = 1.0e-14;
+
+// Parabola `(u, u^2)` over the interval (0, 1).
+fn parabola() -> Bernstein<Complex64, f64, 3> {
+    Bernstein::new([
+        Complex64::new(0.0, 0.0),
+        Complex64::new(0.5, 0.0),
+        Complex64::new(1.0, 1.0),
+    ])
+}
+
+#[test]
+fn frame_of_parabola() {
+    let p = parabola();
+
+    for k in 0..=10 {
+        let u = 0.1 * k as f64;
+        let s = f64::sqrt(1.0 + 4.0 * u * u);
+
+        assert!(equal_with_abs_tol(p.speed(u), s, DBL_TOL));
+
+        let t = p.tangent(u);
+        assert!(equal_with_abs_tol(t.re, 1.0 / s, DBL_TOL));
+        assert!(equal_with_abs_tol(t.im, 2.0 * u / s, DBL_TOL));
+
+        let n = p.normal(u);
+        assert!(equal_with_abs_tol(n.re, -2.0 * u / s, DBL_TOL));
+        assert!(equal_with_abs_tol(n.im, 1.0 / s, DBL_TOL));
+    }
+}
+
+#[test]
+fn frame_of_cubic_complex() {
+    // Cubic `(u, u^3)` over the interval (0, 1).
+    let p: Bernstein<Complex64, f64, 4> = Bernstein::new([
+        Complex64::new(0.0, 0.0),
+        Complex64::new(1.0 / 3.0, 0.0),
+        Complex64::new(2.0 / 3.0, 0.0),
+        Complex64::new(1.0, 1.0),
+    ]);
+    let n = p.curvature_numerator_into::<4>();
+
+    for k in 0..=10 {
+        let u = 0.1 * k as f64;
+        let d1 = Complex64::new(1.0, 3.0 * u * u);
+        let s = d1.norm();
+
+        assert!(equal_with_abs_tol(p.speed(u), s, DBL_TOL));
+        assert!(equal_with_abs_tol(
+            (p.tangent(u) - d1 / s).norm(),
+            0.0,
+            DBL_TOL
+        ));
+        assert!(equal_with_abs_tol(
+            (p.normal(u) - Complex64::new(-3.0 * u * u, 1.0) / s).norm(),
+            0.0,
+            DBL_TOL
+        ));
+        assert!(equal_with_abs_tol(
+            p.curvature(u),
+            6.0 * u / (s * s * s),
+            DBL_TOL
+        ));
+        assert!(equal_with_abs_tol(n.eval(u), 6.0 * u, DBL_TOL));
+    }
+}
+
+// Speed and tangent of a scalar polynomial `u^2` are its absolute derivative
+// and sign of the derivative.
+#[test]
+fn frame_of_scalar_f64() {
+    let p: Bernstein<f64, f64, 3> = Bernstein::new([0.0, 0.0, 1.0]);
+
+    for k in 1..=10 {
+        let u = 0.1 * k as f64;
+        assert!(equal_with_abs_tol(p.speed(u), 2.0 * u, DBL_TOL));
+        assert!(equal_with_abs_tol(p.tangent(u), 1.0, DBL_TOL));
+    }
+}
+
+#[test]
+fn signed_curvature_of_parabola() {
+    let p = parabola();
+    // The same parabola traversed in the opposite direction.
+    let mut c = *p.coef();
+    c.reverse();
+    let q: Bernstein<Complex64, f64, 3> = Bernstein::new(c);
+
+    for k in 0..=10 {
+        let u = 0.1 * k as f64;
+        let kappa = 2.0 / f64::powf(1.0 + 4.0 * u * u, 1.5);
+
+        assert!(equal_with_abs_tol(p.curvature(u), kappa, DBL_TOL));
+        assert!(equal_with_abs_tol(q.curvature(1.0 - u), -kappa, DBL_TOL));
+    }
+}
+
+#[test]
+fn curvature_numerator_complex() {
+    let p: Bernstein<Complex64, f64, 5> = Bernstein::with_interval(
+        [
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 2.0),
+            Complex64::new(3.0, -1.0),
+            Complex64::new(4.0, 1.0),
+            Complex64::new(2.0, 3.0),
+        ],
+        -1.0,
+        1.0,
+    );
+
+    let c = p.curvature_numerator_into::<6>();
+    assert_eq!(c.interval(), (-1.0, 1.0));
+
+    for k in 0..=20 {
+        let u = -1.0 + 0.1 * k as f64;
+        let [_, d1, d2] = p.eval_derivs_into::<3>(u);
+        assert!(equal_with_abs_tol(c.eval(u), d1.cross(&d2), 1.0e-11));
+
+        let s = p.speed(u);
+        assert!(equal_with_abs_tol(
+            c.eval(u) / (s * s * s),
+            p.curvature(u),
+            1.0e-12
+        ));
+    }
+}
+
+#[test]
+#[cfg(feature = "nightly")]
+fn curvature_numerator_size() {
+    let p = parabola();
+    let c = p.curvature_numerator();
+    assert_eq!(c.coef().len(), 2);
+    // Curvature numerator of the parabola is constant.
+    assert!(equal_with_abs_tol(c.coef()[0], 2.0, DBL_TOL));
+    assert!(equal_with_abs_tol(c.coef()[1], 2.0, DBL_TOL));
+}
+
+#[test]
+#[should_panic]
+fn curvature_numerator_line() {
+    let p: Bernstein<Complex64, f64, 2> =
+        Bernstein::new([Complex64::new(0.0, 0.0), Complex64::new(1.0, 1.0)]);
+    let _ = p.curvature_numerator_into::<0>();
+}
@@ -0,0 +1,67 @@
+use num::Rational64;
+
+use bernstein::{Bernstein, Evaluator};
+
+mod routines;
+pub use routines::*;
+
+// Nested multiplication is exact in rational arithmetic, including the end
+// points and the points outside of the interval.
+#[test]
+fn eval_vs_rational() {
+    let p: Bernstein<Rational64, Rational64, 6> = Bernstein::with_interval(
+        [r(1, 2), r(-3, 7), r(5, 3), r(2, 1), r(0, 1), r(-1, 9)],
+        r(-1, 1),
+        r(2, 1),
+    );
+
+    for k in -2..=14 {
+        let u = r(-1, 1) + r(k, 4);
+        assert_eq!(p.eval_vs(u), p.eval(u));
+        assert_eq!(p.eval_with(u, Evaluator::VolkSchumaker), p.eval(u));
+        assert_eq!(p.eval_with(u, Evaluator::DeCasteljau), p.eval(u));
+    }
+}
+
+#[test]
+fn eval_vs_f32() {
+    const FLT_TOL: f64 = 1.0e-5;
+    let p: Bernstein<f32, f32, 4> = Bernstein::new([0.5, -1.5, 2.0, 1.0]);
+
+    for k in 0..=100 {
+        let u = k as f32 / 100.0;
+        assert!(equal_with_abs_tol(
+            p.eval_vs(u) as f64,
+            p.eval(u) as f64,
+            FLT_TOL
+        ));
+    }
+}
+
+// Rounding errors of both algorithms are small for a high degree polynomial
+// with coefficients of the same order.
+#[test]
+fn eval_vs_high_degree_f64() {
+    const DBL_TOL: f64 = 1.0e-13;
+    let coef: [f64; 25] = std::array::from_fn(|i| ((i * 7) % 11) as f64 / 11.0 - 0.5);
+    let p: Bernstein<f64, f64, 25> = Bernstein::with_interval(coef, 1.0, 3.0);
+
+    for k in 0..=200 {
+        let u = 1.0 + k as f64 / 100.0;
+        assert!(equal_with_abs_tol(p.eval_vs(u), p.eval(u), DBL_TOL));
+    }
+
+    // Polynomial with all unit coefficients is identically one.
+    let p: Bernstein<f64, f64, 25> = Bernstein::new([1.0; 25]);
+    for k in 0..=100 {
+        let u = k as f64 / 100.0;
+        assert!(equal_with_abs_tol(p.eval_vs(u), 1.0, DBL_TOL));
+    }
+}
+
+#[test]
+fn eval_vs_constant_f64() {
+    let p: Bernstein<f64, f64, 1> = Bernstein::new([3.0]);
+    assert_eq!(p.eval_vs(0.25), 3.0);
+    assert_eq!(p.eval_vs(1.0), 3.0);
+}
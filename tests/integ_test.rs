@@ -1,3 +1,7 @@
+#![cfg(feature = "nightly")]
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
 use num::{Complex, Rational64, Zero};
 
 use bernstein::Bernstein;
@@ -0,0 +1,141 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+use num::{Rational64, Zero};
+
+use bernstein::Bernstein;
+
+mod routines;
+pub use routines::*;
+
+#[test]
+fn eval_with_interval_f64() {
+    const DBL_TOL: f64 = 1.0e-15;
+    let c: Bernstein<f64, f64, 3> = Bernstein::with_interval([1.0, 2.0, -1.0], -1.0, 3.0);
+    assert_eq!(c.interval(), (-1.0, 3.0));
+    assert!(equal_with_abs_tol(c.eval(-1.0), 1.0, DBL_TOL));
+    assert!(equal_with_abs_tol(c.eval(3.0), -1.0, DBL_TOL));
+    assert!(equal_with_abs_tol(c.eval(1.0), 1.0, DBL_TOL));
+}
+
+#[test]
+fn split_with_interval_f64() {
+    let c: Bernstein<f64, f64, 3> = Bernstein::with_interval([1.0, 2.0, -1.0], 1.0, 2.0);
+    let (l, r) = c.split(1.25);
+    assert_eq!(l.interval(), (1.0, 1.25));
+    assert_eq!(r.interval(), (1.25, 2.0));
+    assert_eq!(c.restrict(1.5, 1.75).interval(), (1.5, 1.75));
+}
+
+// Restriction preserves values of the polynomial, which can be evaluated
+// outside of the original interval.
+#[test]
+fn restrict_larger_interval_rational() {
+    let c: Bernstein<Rational64, Rational64, 4> = Bernstein::new([
+        Rational64::new(1, 5),
+        Rational64::new(-3, 7),
+        Rational64::new(4, 13),
+        Rational64::new(-11, 17),
+    ]);
+
+    let a = Rational64::new(-2, 3);
+    let b = Rational64::new(5, 2);
+    let r = c.restrict(a, b);
+    assert_eq!(r.interval(), (a, b));
+
+    for k in 0..=10 {
+        let u = a + (b - a) * Rational64::new(k, 10);
+        assert_eq!(r.eval(u), c.eval(u));
+    }
+
+    let r = r.restrict(Rational64::zero(), Rational64::new(1, 1));
+    assert_eq!(r.coef().clone(), c.coef().clone());
+}
+
+// Rescaling preserves coefficients, so that the curve is the same, and
+// derivatives and integrals are scaled accordingly.
+#[test]
+fn rescale_rational() {
+    let coef = [
+        Rational64::new(1, 13),
+        Rational64::new(-3, 11),
+        Rational64::new(1, 7),
+    ];
+    let c: Bernstein<Rational64, Rational64, 3> = Bernstein::new(coef);
+
+    let a = Rational64::new(1, 1);
+    let b = Rational64::new(5, 1);
+    let s = c.rescale(a, b);
+    assert_eq!(s.interval(), (a, b));
+    assert_eq!(s.coef().clone(), coef);
+
+    for k in 0..=8 {
+        let t = Rational64::new(k, 8);
+        let u = a + (b - a) * t;
+        assert_eq!(s.eval(u), c.eval(t));
+        assert_eq!(
+            s.diff_into::<2>().eval(u) * (b - a),
+            c.diff_into::<2>().eval(t)
+        );
+        assert_eq!(
+            s.integ_into::<4>(Rational64::zero()).eval(u),
+            c.integ_into::<4>(Rational64::zero()).eval(t) * (b - a)
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "interval has zero length")]
+fn with_interval_zero_length() {
+    let _: Bernstein<f64, f64, 2> = Bernstein::with_interval([1.0, 2.0], 1.0, 1.0);
+}
+
+// Reparametrization keeps the whole curve, while restriction to the same
+// interval keeps the values at each parameter.
+#[test]
+fn reparametrize_rational() {
+    let a0 = Rational64::new(-1, 2);
+    let b0 = Rational64::new(3, 2);
+    let c: Bernstein<Rational64, Rational64, 3> = Bernstein::with_interval(
+        [
+            Rational64::new(1, 13),
+            Rational64::new(-3, 11),
+            Rational64::new(1, 7),
+        ],
+        a0,
+        b0,
+    );
+
+    let a = Rational64::new(2, 1);
+    let b = Rational64::new(7, 3);
+    let p = c.reparametrize(a, b);
+    assert_eq!(p.interval(), (a, b));
+    assert_eq!(*p.coef(), *c.coef());
+    assert_ne!(*p.coef(), *c.restrict(a, b).coef());
+
+    for k in 0..=8 {
+        let t = Rational64::new(k, 8);
+        assert_eq!(p.eval(a + (b - a) * t), c.eval(a0 + (b0 - a0) * t));
+    }
+}
+
+#[test]
+#[should_panic(expected = "interval has zero length")]
+fn rescale_zero_length() {
+    let c: Bernstein<f64, f64, 2> = Bernstein::new([1.0, 2.0]);
+    let _ = c.rescale(0.5, 0.5);
+}
+
+#[test]
+#[should_panic(expected = "interval has zero length")]
+fn restrict_zero_length() {
+    let c: Bernstein<f64, f64, 2> = Bernstein::new([1.0, 2.0]);
+    let _ = c.restrict(0.5, 0.5);
+}
+
+#[test]
+#[should_panic(expected = "interval has zero length")]
+fn split_at_end() {
+    let c: Bernstein<f64, f64, 2> = Bernstein::new([1.0, 2.0]);
+    let _ = c.split(1.0);
+}
@@ -1,3 +1,4 @@
+#![cfg(feature = "nightly")]
 #![allow(incomplete_features)]
 #![feature(generic_const_exprs)]
 
@@ -118,4 +119,36 @@ fn mul_right_by_scalar() {
     let coef = p.coef().clone();
     assert_eq!(coef[0], Rational64::new(1, 1));
     assert_eq!(coef[1], Rational64::new(3, 2));
-}
\ No newline at end of file
+}
+
+#[test]
+#[should_panic]
+fn product_different_intervals() {
+    let p: Bernstein<f64, f64, 2> = Bernstein::new([1.0, 2.0]);
+    let q: Bernstein<f64, f64, 2> = Bernstein::with_interval([1.0, 2.0], 0.0, 2.0);
+    let _ = p * q;
+}
+
+#[test]
+fn product_over_interval_rational() {
+    let a = Rational64::new(-1, 2);
+    let b = Rational64::new(3, 1);
+    let p: Bernstein<Rational64, Rational64, 2> =
+        Bernstein::with_interval([Rational64::new(1, 3), Rational64::new(2, 1)], a, b);
+    let q: Bernstein<Rational64, Rational64, 3> = Bernstein::with_interval(
+        [
+            Rational64::new(-1, 1),
+            Rational64::new(1, 7),
+            Rational64::new(5, 2),
+        ],
+        a,
+        b,
+    );
+
+    let c = p * q;
+    assert_eq!(c.interval(), (a, b));
+    for k in 0..=7 {
+        let u = a + (b - a) * Rational64::new(k, 7);
+        assert_eq!(c.eval(u), p.eval(u) * q.eval(u));
+    }
+}
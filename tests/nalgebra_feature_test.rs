@@ -0,0 +1,181 @@
+// Tests of the traits implemented for the vectors of `nalgebra`, which are
+// only run with `cargo test --features nalgebra`.
+#![cfg(feature = "nalgebra")]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+use bernstein::{Bernstein, BezierSpline, Constraint, Continuity};
+
+use nalgebra::Vector3;
+
+mod routines;
+pub use routines::*;
+
+#[test]
+fn nalgebra_bounding_box() {
+    const DBL_TOL: f64 = 1.0e-15;
+    let p0: Vector3<f64> = Vector3::new(0.0, 0.0, 1.0);
+    let p1: Vector3<f64> = Vector3::new(1.0, 2.0, -1.0);
+    let p2: Vector3<f64> = Vector3::new(2.0, 0.0, 1.0);
+
+    let c: Bernstein<_, f64, 3> = Bernstein::new([p0, p1, p2]);
+
+    let (lo, hi) = c.control_polygon_bounds();
+    assert!(vector3_equal_with_abs_tol(
+        lo,
+        Vector3::new(0.0, 0.0, -1.0),
+        DBL_TOL
+    ));
+    assert!(vector3_equal_with_abs_tol(
+        hi,
+        Vector3::new(2.0, 2.0, 1.0),
+        DBL_TOL
+    ));
+
+    let (lo, hi) = c.bounding_box();
+    assert!(vector3_equal_with_abs_tol(
+        lo,
+        Vector3::new(0.0, 0.0, 0.0),
+        DBL_TOL
+    ));
+    assert!(vector3_equal_with_abs_tol(
+        hi,
+        Vector3::new(2.0, 1.0, 1.0),
+        DBL_TOL
+    ));
+}
+
+#[test]
+fn nalgebra_dot_cross_scale() {
+    const DBL_TOL: f64 = 1.0e-12;
+    let p: Bernstein<Vector3<f64>, f64, 3> = Bernstein::new([
+        Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(1.0, 2.0, -1.0),
+        Vector3::new(2.0, 0.0, 1.0),
+    ]);
+    let q: Bernstein<Vector3<f64>, f64, 2> =
+        Bernstein::new([Vector3::new(1.0, -1.0, 0.5), Vector3::new(0.0, 3.0, 2.0)]);
+    let s: Bernstein<f64, f64, 3> = Bernstein::new([1.0, -2.0, 0.5]);
+
+    let d = p.dot_into::<2, 4>(&q);
+    let c = p.cross_into::<2, 4>(&q);
+    let m = p.scale_by_into::<3, 5>(&s);
+
+    for k in 0..=10 {
+        let u = 0.1 * k as f64;
+        let (a, b) = (p.eval(u), q.eval(u));
+        assert!(equal_with_abs_tol(d.eval(u), a.dot(&b), DBL_TOL));
+        assert!(vector3_equal_with_abs_tol(c.eval(u), a.cross(&b), DBL_TOL));
+        assert!(vector3_equal_with_abs_tol(
+            m.eval(u),
+            a * s.eval(u),
+            DBL_TOL
+        ));
+    }
+}
+
+#[test]
+fn nalgebra_frenet_frame() {
+    const DBL_TOL: f64 = 1.0e-13;
+    // Twisted cubic `(u, u^2, u^3)` over the interval (0, 1).
+    let p: Bernstein<Vector3<f64>, f64, 4> = Bernstein::new([
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0 / 3.0, 0.0, 0.0),
+        Vector3::new(2.0 / 3.0, 1.0 / 3.0, 0.0),
+        Vector3::new(1.0, 1.0, 1.0),
+    ]);
+
+    for k in 0..=10 {
+        let u = 0.1 * k as f64;
+        let d1 = Vector3::new(1.0, 2.0 * u, 3.0 * u * u);
+        let b = Vector3::new(6.0 * u * u, -6.0 * u, 2.0);
+
+        assert!(equal_with_abs_tol(p.speed(u), d1.norm(), DBL_TOL));
+        assert!(vector3_equal_with_abs_tol(
+            p.tangent(u),
+            d1.normalize(),
+            DBL_TOL
+        ));
+        assert!(vector3_equal_with_abs_tol(
+            p.binormal(u),
+            b.normalize(),
+            DBL_TOL
+        ));
+        assert!(vector3_equal_with_abs_tol(
+            p.normal(u),
+            b.normalize().cross(&d1.normalize()),
+            DBL_TOL
+        ));
+        assert!(equal_with_abs_tol(
+            p.curvature(u),
+            b.norm() / d1.norm().powi(3),
+            DBL_TOL
+        ));
+        assert!(equal_with_abs_tol(
+            p.torsion(u),
+            3.0 / (9.0 * u.powi(4) + 9.0 * u * u + 1.0),
+            DBL_TOL
+        ));
+
+        let n = p.curvature_numerator_into::<4>();
+        assert!(vector3_equal_with_abs_tol(n.eval(u), b, DBL_TOL));
+    }
+}
+
+// Reduction of an elevated curve recovers the original one, and the error of
+// the linear approximation of the parabola is the distance from the chord.
+#[test]
+fn nalgebra_reduce() {
+    const DBL_TOL: f64 = 1.0e-12;
+    let p0: Vector3<f64> = Vector3::new(0.0, 0.0, 1.0);
+    let p1: Vector3<f64> = Vector3::new(1.0, 2.0, -1.0);
+    let p2: Vector3<f64> = Vector3::new(2.0, 0.0, 1.0);
+    let p3: Vector3<f64> = Vector3::new(3.0, 1.0, 0.0);
+
+    let c: Bernstein<_, f64, 4> = Bernstein::new([p0, p1, p2, p3]);
+    let e: Bernstein<_, f64, 6> = c.elevate_to();
+
+    let (r, err) = e.reduce_to::<4>(Constraint::Free);
+    for (a, b) in r.coef().iter().zip(c.coef()) {
+        assert!(vector3_equal_with_abs_tol(*a, *b, DBL_TOL));
+    }
+    assert!(equal_with_abs_tol(err, 0.0, DBL_TOL));
+
+    let q: Bernstein<_, f64, 3> = Bernstein::new([p0, p1, p2]);
+    let (r, err) = q.reduce_to::<2>(Constraint::C0);
+    assert!(vector3_equal_with_abs_tol(r.coef()[0], p0, DBL_TOL));
+    assert!(vector3_equal_with_abs_tol(r.coef()[1], p2, DBL_TOL));
+    assert!(equal_with_abs_tol(
+        err,
+        0.5 * (p1 - 0.5 * (p0 + p2)).norm(),
+        DBL_TOL
+    ));
+}
+
+#[test]
+fn nalgebra_spline_continuity() {
+    const DBL_TOL: f64 = 1.0e-14;
+    let coef = vec![
+        [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(2.0, 0.0, 1.0),
+        ],
+        [
+            Vector3::new(2.0, 0.5, 1.0),
+            Vector3::new(3.0, -1.0, 0.0),
+            Vector3::new(4.0, 0.0, 0.0),
+        ],
+    ];
+    let s: BezierSpline<Vector3<f64>, f64, 3> = BezierSpline::from_coef(coef, &[0.0, 1.0, 3.0]);
+    assert!(!s.is_continuous(Continuity::C0, DBL_TOL));
+
+    let mut t = s.clone();
+    t.enforce(Continuity::C1);
+    assert!(t.is_continuous(Continuity::C1, DBL_TOL));
+
+    let mut t = s.clone();
+    t.enforce_g1();
+    assert!(t.is_continuous(Continuity::G1, DBL_TOL));
+    assert!(!t.is_continuous(Continuity::C1, DBL_TOL));
+}
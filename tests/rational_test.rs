@@ -0,0 +1,182 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+#[cfg(feature = "nightly")]
+use num::complex::Complex64;
+use num::Rational64;
+
+#[cfg(feature = "nightly")]
+use bernstein::Bernstein;
+use bernstein::RationalBernstein;
+
+mod routines;
+pub use routines::*;
+
+#[test]
+#[cfg(feature = "nightly")]
+fn rational_unit_weights_f64() {
+    const DBL_TOL: f64 = 1.0e-15;
+    let coef = [1.0, 2.0, -1.0];
+    let c: RationalBernstein<f64, f64, 3> = RationalBernstein::new(coef, [1.0, 1.0, 1.0]);
+    let p: Bernstein<f64, f64, 3> = Bernstein::new(coef);
+
+    for k in 0..=10 {
+        let u = k as f64 / 10.0;
+        assert!(equal_with_abs_tol(c.eval(u), p.eval(u), DBL_TOL));
+        assert!(equal_with_abs_tol(
+            c.diff().eval(u),
+            p.diff().eval(u),
+            4.0 * DBL_TOL
+        ));
+    }
+}
+
+#[test]
+#[cfg(feature = "nightly")]
+fn rational_circular_arc() {
+    const DBL_TOL: f64 = 1.0e-14;
+    let center = Complex64::new(1.0, -2.0);
+    let radius = 3.0;
+    let start = 0.25;
+    let sweep = 2.0;
+
+    let c = RationalBernstein::circular_arc(center, radius, start, sweep);
+
+    let p0 = c.eval(0.0);
+    let p1 = c.eval(1.0);
+    assert!(equal_with_abs_tol((p0 - center).arg(), start, DBL_TOL));
+    assert!(equal_with_abs_tol(
+        (p1 - center).arg(),
+        start + sweep,
+        DBL_TOL
+    ));
+
+    let d = c.diff();
+    for k in 0..=20 {
+        let u = k as f64 / 20.0;
+        let p = c.eval(u);
+        // All points are on the circle, and tangents are orthogonal to radii.
+        assert!(equal_with_abs_tol((p - center).norm(), radius, DBL_TOL));
+        let t = d.eval(u);
+        assert!(equal_with_abs_tol(
+            (t.conj() * (p - center)).re,
+            0.0,
+            10.0 * DBL_TOL
+        ));
+    }
+}
+
+#[test]
+#[cfg(feature = "nightly")]
+fn rational_diff_finite_difference() {
+    let c: RationalBernstein<Complex64, f64, 4> = RationalBernstein::with_interval(
+        [
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 2.0),
+            Complex64::new(3.0, -1.0),
+            Complex64::new(4.0, 0.5),
+        ],
+        [1.0, 0.5, 2.0, 1.5],
+        1.0,
+        3.0,
+    );
+    let d = c.diff();
+    assert_eq!(d.interval(), (1.0, 3.0));
+
+    const H: f64 = 1.0e-6;
+    for k in 1..10 {
+        let u = 1.0 + k as f64 / 5.0;
+        let fd = (c.eval(u + H) - c.eval(u - H)) / (2.0 * H);
+        assert!(equal_with_abs_tol((d.eval(u) - fd).norm(), 0.0, 1.0e-8));
+    }
+}
+
+#[test]
+fn rational_split_rational() {
+    let c: RationalBernstein<Rational64, Rational64, 3> = RationalBernstein::conic(
+        Rational64::new(1, 2),
+        Rational64::new(-3, 7),
+        Rational64::new(2, 1),
+        Rational64::new(3, 2),
+    );
+    let u = Rational64::new(1, 3);
+    let (l, r) = c.split(u);
+    assert_eq!(l.interval(), (Rational64::new(0, 1), u));
+    assert_eq!(r.interval(), (u, Rational64::new(1, 1)));
+
+    for k in 0..=6 {
+        let v = Rational64::new(k, 18);
+        assert_eq!(l.eval(v), c.eval(v));
+        let v = Rational64::new(6 + 2 * k, 18);
+        assert_eq!(r.eval(v), c.eval(v));
+    }
+}
+
+#[test]
+fn rational_homogeneous_rational() {
+    let coef = [
+        Rational64::new(1, 2),
+        Rational64::new(-3, 7),
+        Rational64::new(2, 1),
+    ];
+    let weight = [
+        Rational64::new(1, 1),
+        Rational64::new(1, 3),
+        Rational64::new(2, 1),
+    ];
+    let c: RationalBernstein<Rational64, Rational64, 3> = RationalBernstein::new(coef, weight);
+
+    let (num, den) = c.to_homogeneous();
+    assert_eq!(
+        num.coef().clone(),
+        [
+            Rational64::new(1, 2),
+            Rational64::new(-1, 7),
+            Rational64::new(4, 1)
+        ]
+    );
+    assert_eq!(den.coef().clone(), weight);
+
+    let h = RationalBernstein::from_homogeneous(num, den);
+    assert_eq!(h.coef().clone(), coef);
+    assert_eq!(h.weight().clone(), weight);
+
+    for k in 0..=5 {
+        let u = Rational64::new(k, 5);
+        assert_eq!(c.eval(u), num.eval(u) / den.eval(u));
+    }
+}
+
+// Derivative by the quotient rule of the homogeneous polynomials, which is
+// available without the `nightly` feature.
+#[test]
+fn rational_diff_into_rational() {
+    let coef = [
+        Rational64::new(1, 2),
+        Rational64::new(-3, 7),
+        Rational64::new(2, 1),
+    ];
+    let weight = [
+        Rational64::new(1, 1),
+        Rational64::new(1, 3),
+        Rational64::new(2, 1),
+    ];
+    let a = Rational64::new(-1, 1);
+    let b = Rational64::new(2, 1);
+    let c: RationalBernstein<Rational64, Rational64, 3> =
+        RationalBernstein::with_interval(coef, weight, a, b);
+
+    let d = c.diff_into::<5>();
+    assert_eq!(d.interval(), (a, b));
+
+    let (num, den) = c.to_homogeneous();
+    let (dnum, dden) = (num.diff_into::<2>(), den.diff_into::<2>());
+    for k in 0..=6 {
+        let u = a + (b - a) * Rational64::new(k, 6);
+        let w = den.eval(u);
+        assert_eq!(
+            d.eval(u),
+            (dnum.eval(u) * w - num.eval(u) * dden.eval(u)) / (w * w)
+        );
+    }
+}
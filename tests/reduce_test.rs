@@ -0,0 +1,120 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+use num::complex::Complex64;
+use num::{Rational64, Zero};
+
+use bernstein::{Bernstein, Constraint};
+
+mod routines;
+pub use routines::*;
+
+// Reduction of an elevated polynomial recovers the original one exactly.
+#[test]
+fn reduce_elevated_rational() {
+    let coef = [
+        Rational64::new(1, 5),
+        Rational64::new(-3, 7),
+        Rational64::new(4, 13),
+        Rational64::new(-11, 17),
+    ];
+    let c: Bernstein<Rational64, Rational64, 4> = Bernstein::new(coef);
+    let e: Bernstein<Rational64, Rational64, 7> = c.elevate_to();
+
+    for constraint in [Constraint::Free, Constraint::C0, Constraint::C1] {
+        let (r, err) = e.reduce_to::<4>(constraint);
+        assert_eq!(r.coef().clone(), coef);
+        assert_eq!(err, Rational64::zero());
+    }
+}
+
+// Best linear approximation of `u^2` over (0, 1) in the L2 sense
+// is `u - 1/6`.
+#[test]
+fn reduce_quadratic_to_linear_rational() {
+    let c: Bernstein<Rational64, Rational64, 3> = Bernstein::new([
+        Rational64::zero(),
+        Rational64::zero(),
+        Rational64::new(1, 1),
+    ]);
+
+    let (r, err) = c.reduce_to::<2>(Constraint::Free);
+    assert_eq!(
+        r.coef().clone(),
+        [Rational64::new(-1, 6), Rational64::new(5, 6)]
+    );
+    assert_eq!(err, Rational64::new(1, 6));
+
+    // With interpolation of the end points, the chord is the only choice.
+    let (r, err) = c.reduce_to::<2>(Constraint::C0);
+    assert_eq!(
+        r.coef().clone(),
+        [Rational64::zero(), Rational64::new(1, 1)]
+    );
+    assert_eq!(err, Rational64::new(1, 4));
+}
+
+// Reduce quintic Pythagorean-hodograph curve to a cubic.
+#[test]
+fn reduce_quintic_to_cubic_complex() {
+    const DBL_TOL: f64 = 1.0e-14;
+
+    let rho = f64::sqrt(
+        30.0 * f64::cos(0.25 * std::f64::consts::PI)
+            / (6.0 * f64::cos(0.25 * std::f64::consts::PI) + 1.0),
+    );
+    let w0 = Complex64::new(rho, 0.0);
+    let w1 = Complex64::zero();
+    let w2 = rho * Complex64::exp(0.25 * std::f64::consts::PI * Complex64::i());
+
+    let w: Bernstein<Complex64, f64, 3> = Bernstein::new([w0, w1, w2]);
+    let p = w
+        .mul_into::<3, 5>(&w)
+        .integ_into::<6>(Complex64::new(-1.0, 0.0));
+
+    let (free, free_err) = p.reduce_to::<4>(Constraint::Free);
+    let (c0, c0_err) = p.reduce_to::<4>(Constraint::C0);
+    let (c1, c1_err) = p.reduce_to::<4>(Constraint::C1);
+
+    // End points and tangents are interpolated.
+    for r in [c0, c1] {
+        assert!(equal_with_abs_tol(
+            (r.eval(0.0) - p.eval(0.0)).norm(),
+            0.0,
+            DBL_TOL
+        ));
+        assert!(equal_with_abs_tol(
+            (r.eval(1.0) - p.eval(1.0)).norm(),
+            0.0,
+            DBL_TOL
+        ));
+    }
+    let (dp, dc1) = (p.diff_into::<5>(), c1.diff_into::<3>());
+    assert!(equal_with_abs_tol(
+        (dc1.eval(0.0) - dp.eval(0.0)).norm(),
+        0.0,
+        DBL_TOL
+    ));
+    assert!(equal_with_abs_tol(
+        (dc1.eval(1.0) - dp.eval(1.0)).norm(),
+        0.0,
+        DBL_TOL
+    ));
+
+    // Errors agree with the maximum error over a dense grid.
+    for (r, err) in [(free, free_err), (c0, c0_err), (c1, c1_err)] {
+        assert!(err > 0.0);
+        let max = (0..=1000)
+            .map(|k| (r.eval(k as f64 / 1000.0) - p.eval(k as f64 / 1000.0)).norm())
+            .fold(0.0, f64::max);
+        assert!(err <= max + DBL_TOL);
+        assert!(equal_with_abs_tol(err / max, 1.0, 1.0e-3));
+    }
+}
+
+#[test]
+#[should_panic]
+fn reduce_c1_to_quadratic() {
+    let c: Bernstein<f64, f64, 4> = Bernstein::new([1.0, 2.0, 0.0, 1.0]);
+    let _ = c.reduce_to::<3>(Constraint::C1);
+}
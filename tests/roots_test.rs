@@ -0,0 +1,97 @@
+#![cfg(feature = "nightly")]
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+use bernstein::Bernstein;
+
+mod routines;
+pub use routines::*;
+
+// Linear factor `u - r` in the Bernstein basis over (0, 1).
+fn factor(r: f64) -> Bernstein<f64, f64, 2> {
+    Bernstein::new([-r, 1.0 - r])
+}
+
+#[test]
+fn roots_simple_f64() {
+    const TOL: f64 = 1.0e-12;
+    let p = factor(0.2) * factor(0.5) * factor(0.7);
+    let roots = p.roots(TOL);
+
+    assert_eq!(roots.len(), 3);
+    for ((r, m), e) in roots.iter().zip([0.2, 0.5, 0.7]) {
+        assert!(equal_with_abs_tol(*r, e, 2.0 * TOL));
+        assert_eq!(*m, 1);
+    }
+}
+
+#[test]
+fn roots_multiple_f64() {
+    const TOL: f64 = 1.0e-6;
+    let p = factor(0.3) * factor(0.3) * factor(0.8);
+    let roots = p.roots(TOL);
+
+    assert_eq!(roots.len(), 2);
+    assert!(equal_with_abs_tol(roots[0].0, 0.3, 2.0 * TOL));
+    assert_eq!(roots[0].1, 2);
+    assert!(equal_with_abs_tol(roots[1].0, 0.8, 2.0 * TOL));
+    assert_eq!(roots[1].1, 1);
+}
+
+#[test]
+fn roots_at_end_points_f64() {
+    let p = factor(0.0) * factor(0.0) * factor(1.0);
+    assert_eq!(p.roots(1.0e-12), vec![(0.0, 2), (1.0, 1)]);
+
+    // Root at the point of subdivision.
+    let p = factor(0.5) * factor(0.25);
+    let roots = p.roots(1.0e-12);
+    assert_eq!(roots.len(), 2);
+    assert!(equal_with_abs_tol(roots[0].0, 0.25, 1.0e-12));
+    assert_eq!(roots[1], (0.5, 1));
+}
+
+#[test]
+fn roots_none_f64() {
+    // Strictly positive polynomial.
+    let p: Bernstein<f64, f64, 3> = Bernstein::new([1.0, -0.5, 1.0]);
+    assert!(p.roots(1.0e-12).is_empty());
+
+    // Roots outside of the interval.
+    let p = factor(-0.5) * factor(1.5);
+    assert!(p.roots(1.0e-12).is_empty());
+
+    let p: Bernstein<f64, f64, 3> = Bernstein::new([0.0, 0.0, 0.0]);
+    assert!(p.roots(1.0e-12).is_empty());
+}
+
+#[test]
+fn roots_with_interval_f64() {
+    const TOL: f64 = 1.0e-12;
+    let p = (factor(0.25) * factor(0.75) * factor(2.5)).restrict(-1.0, 3.0);
+    let roots = p.roots(TOL);
+    assert_eq!(roots.len(), 3);
+    for ((r, _), e) in roots.iter().zip([0.25, 0.75, 2.5]) {
+        assert!(equal_with_abs_tol(*r, e, 4.0 * TOL));
+    }
+}
+
+#[test]
+fn roots_high_order_f64() {
+    const TOL: f64 = 1.0e-10;
+    let expected = [0.05, 0.15, 0.35, 0.45, 0.55, 0.6, 0.8, 0.95];
+    let p = factor(expected[0])
+        * factor(expected[1])
+        * factor(expected[2])
+        * factor(expected[3])
+        * factor(expected[4])
+        * factor(expected[5])
+        * factor(expected[6])
+        * factor(expected[7]);
+    let roots = p.roots(TOL);
+    assert_eq!(roots.len(), expected.len());
+    for ((r, m), e) in roots.iter().zip(expected) {
+        assert!(equal_with_abs_tol(*r, e, 1.0e-8));
+        assert_eq!(*m, 1);
+    }
+}
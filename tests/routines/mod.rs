@@ -1,4 +1,7 @@
 use nalgebra::Vector3;
+use num::Rational64;
+
+use bernstein::Bernstein;
 
 pub fn equal_with_abs_tol(x: f64, y: f64, tol: f64) -> bool {
     (x - y).abs() < tol
@@ -6,4 +9,13 @@ pub fn equal_with_abs_tol(x: f64, y: f64, tol: f64) -> bool {
 
 pub fn vector3_equal_with_abs_tol(v: Vector3<f64>, w: Vector3<f64>, tol: f64) -> bool {
     (v - w).norm() < tol
-}
\ No newline at end of file
+}
+
+pub fn r(n: i64, d: i64) -> Rational64 {
+    Rational64::new(n, d)
+}
+
+// Cubic polynomial with exact coefficients over the interval (-1, 2).
+pub fn cubic() -> Bernstein<Rational64, Rational64, 4> {
+    Bernstein::with_interval([r(1, 2), r(-3, 7), r(5, 3), r(2, 1)], r(-1, 1), r(2, 1))
+}
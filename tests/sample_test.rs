@@ -0,0 +1,80 @@
+use num::complex::Complex64;
+
+use bernstein::Bernstein;
+
+mod routines;
+pub use routines::*;
+
+#[test]
+fn eval_many_rational() {
+    let p = cubic();
+    let u: Vec<_> = (0..=12).map(|k| r(-1, 1) + r(k, 4)).collect();
+    let mut out = vec![r(0, 1); u.len()];
+
+    p.eval_many(&u, &mut out);
+    for (x, y) in u.iter().zip(&out) {
+        assert_eq!(*y, p.eval(*x));
+    }
+}
+
+#[test]
+#[should_panic]
+fn eval_many_different_lengths() {
+    let p: Bernstein<f64, f64, 2> = Bernstein::new([0.0, 1.0]);
+    let mut out = [0.0; 2];
+    p.eval_many(&[0.0, 0.5, 1.0], &mut out);
+}
+
+#[test]
+fn sample_rational() {
+    let p = cubic();
+
+    let s = p.sample(7);
+    assert_eq!(s.len(), 7);
+    let values: Vec<_> = s.collect();
+    for (k, v) in values.iter().enumerate() {
+        assert_eq!(*v, p.eval(r(-1, 1) + r(k as i64, 2)));
+    }
+
+    assert_eq!(p.sample(1).collect::<Vec<_>>(), vec![p.eval(r(-1, 1))]);
+    assert_eq!(p.sample(0).count(), 0);
+}
+
+// Forward differencing is exact in rational arithmetic.
+#[test]
+fn forward_differences_rational() {
+    let p = cubic();
+
+    let f = p.forward_differences(13);
+    assert_eq!(f.len(), 13);
+    for (x, y) in f.zip(p.sample(13)) {
+        assert_eq!(x, y);
+    }
+
+    // Fewer points than the size of the polynomial.
+    let values: Vec<_> = p.forward_differences(2).collect();
+    assert_eq!(values, vec![p.eval(r(-1, 1)), p.eval(r(2, 1))]);
+}
+
+#[test]
+fn forward_differences_complex_f64() {
+    const DBL_TOL: f64 = 1.0e-8;
+    let p: Bernstein<Complex64, f64, 4> = Bernstein::new([
+        Complex64::new(0.0, 0.0),
+        Complex64::new(2.5, 1.0),
+        Complex64::new(-0.5, 1.0),
+        Complex64::new(2.0, 0.0),
+    ]);
+
+    // Rounding errors accumulate with the number of points.
+    for (x, y) in p.forward_differences(2001).zip(p.sample(2001)) {
+        assert!(equal_with_abs_tol((x - y).norm(), 0.0, DBL_TOL));
+    }
+}
+
+#[test]
+#[should_panic]
+fn forward_differences_empty() {
+    let p: Bernstein<f64, f64, 0> = Bernstein::new([]);
+    let _ = p.forward_differences(3);
+}
@@ -0,0 +1,137 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+use num::complex::Complex64;
+use num::{Rational64, Zero};
+
+use bernstein::{Bernstein, BezierSpline, Continuity};
+
+mod routines;
+pub use routines::*;
+
+#[test]
+fn spline_segment_lookup() {
+    let s: BezierSpline<f64, f64, 2> = BezierSpline::from_coef(
+        vec![[0.0, 1.0], [1.0, 3.0], [3.0, 2.0]],
+        &[0.0, 1.0, 1.5, 3.0],
+    );
+
+    assert_eq!(s.interval(), (0.0, 3.0));
+    assert_eq!(s.segments().len(), 3);
+    assert_eq!(s.segment_index(-1.0), 0);
+    assert_eq!(s.segment_index(0.5), 0);
+    assert_eq!(s.segment_index(1.0), 0);
+    assert_eq!(s.segment_index(1.2), 1);
+    assert_eq!(s.segment_index(2.0), 2);
+    assert_eq!(s.segment_index(4.0), 2);
+    assert_eq!(s.segment(1.2).interval(), (1.0, 1.5));
+}
+
+#[test]
+fn spline_eval_rational() {
+    let s: BezierSpline<Rational64, Rational64, 3> = BezierSpline::from_coef(
+        vec![[r(0, 1), r(1, 2), r(1, 1)], [r(1, 1), r(3, 2), r(1, 3)]],
+        &[r(0, 1), r(1, 1), r(3, 1)],
+    );
+
+    let p: Bernstein<Rational64, Rational64, 3> =
+        Bernstein::with_interval([r(1, 1), r(3, 2), r(1, 3)], r(1, 1), r(3, 1));
+    assert_eq!(s.eval(r(2, 1)), p.eval(r(2, 1)));
+    assert_eq!(s.eval(r(1, 1)), r(1, 1));
+    assert_eq!(s.eval(r(1, 2)), r(1, 2));
+}
+
+#[test]
+fn spline_diff_integ_rational() {
+    let s: BezierSpline<Rational64, Rational64, 3> = BezierSpline::from_coef(
+        vec![[r(0, 1), r(1, 2), r(1, 1)], [r(2, 1), r(3, 2), r(1, 3)]],
+        &[r(0, 1), r(1, 1), r(3, 1)],
+    );
+
+    // Integral is continuous even if the original function is not.
+    let i = s.integ_into::<4>(r(1, 1));
+    assert_eq!(i.eval(r(0, 1)), r(1, 1));
+    assert!(i.is_continuous(Continuity::C0, Rational64::zero()));
+    assert!(!s.is_continuous(Continuity::C0, Rational64::zero()));
+
+    let d = i.diff_into::<3>();
+    for k in 0..=6 {
+        let u = r(k, 2);
+        assert_eq!(d.eval(u), s.eval(u));
+    }
+}
+
+#[test]
+fn spline_enforce_continuity_complex() {
+    const DBL_TOL: f64 = 1.0e-14;
+    let coef = vec![
+        [
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(2.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ],
+        [
+            Complex64::new(3.0, 0.5),
+            Complex64::new(3.0, -1.0),
+            Complex64::new(4.0, -2.0),
+            Complex64::new(5.0, 0.0),
+        ],
+        [
+            Complex64::new(5.0, 0.0),
+            Complex64::new(6.0, 1.0),
+            Complex64::new(7.0, 1.0),
+            Complex64::new(8.0, 0.0),
+        ],
+    ];
+    let s: BezierSpline<Complex64, f64, 4> = BezierSpline::from_coef(coef, &[0.0, 1.0, 3.0, 3.5]);
+    assert!(!s.is_continuous(Continuity::C0, DBL_TOL));
+
+    for continuity in [
+        Continuity::C0,
+        Continuity::G1,
+        Continuity::C1,
+        Continuity::C2,
+    ] {
+        let mut t = s.clone();
+        t.enforce(continuity);
+        assert!(t.is_continuous(continuity, DBL_TOL));
+        assert_eq!(t.segments()[0].coef(), s.segments()[0].coef());
+    }
+
+    // C2 implies C1 which implies G1 and C0.
+    let mut t = s.clone();
+    t.enforce(Continuity::C2);
+    assert!(t.is_continuous(Continuity::C1, DBL_TOL));
+    assert!(t.is_continuous(Continuity::G1, DBL_TOL));
+
+    // G1 with the lengths of the legs preserved is weaker than C1.
+    let mut t = s.clone();
+    t.enforce_g1();
+    assert!(t.is_continuous(Continuity::G1, DBL_TOL));
+    assert!(!t.is_continuous(Continuity::C1, DBL_TOL));
+    assert_eq!(t.segments()[0].coef(), s.segments()[0].coef());
+
+    // Derivatives are continuous with respect to the global parameter.
+    let mut t = s.clone();
+    t.enforce(Continuity::C1);
+    let d = t.diff_into::<3>();
+    let e = 1.0e-9;
+    assert!((d.eval(1.0 - e) - d.eval(1.0 + e)).norm() < 1.0e-6);
+    assert!((d.eval(3.0 - e) - d.eval(3.0 + e)).norm() < 1.0e-6);
+}
+
+#[test]
+#[should_panic]
+fn spline_not_consecutive() {
+    let p: Bernstein<f64, f64, 2> = Bernstein::new([0.0, 1.0]);
+    let q: Bernstein<f64, f64, 2> = Bernstein::with_interval([0.0, 1.0], 2.0, 3.0);
+    let _ = BezierSpline::new(vec![p, q]);
+}
+
+#[test]
+#[should_panic]
+fn spline_continuity_of_empty_segments() {
+    let s: BezierSpline<f64, f64, 0> = BezierSpline::from_coef(vec![[], []], &[0.0, 1.0, 2.0]);
+    let _ = s.is_continuous(Continuity::C0, 0.0);
+}
@@ -0,0 +1,76 @@
+use num::Rational64;
+
+use bernstein::Bernstein;
+
+mod routines;
+pub use routines::*;
+
+#[test]
+fn split_second_order_f64() {
+    const DBL_TOL: f64 = 1.0e-15;
+    let c: Bernstein<f64, f64, 3> = Bernstein::new([0.0, 2.0, 0.0]);
+    let (l, r) = c.split(0.5);
+
+    let left = *l.coef();
+    assert!(equal_with_abs_tol(left[0], 0.0, DBL_TOL));
+    assert!(equal_with_abs_tol(left[1], 1.0, DBL_TOL));
+    assert!(equal_with_abs_tol(left[2], 1.0, DBL_TOL));
+
+    let right = *r.coef();
+    assert!(equal_with_abs_tol(right[0], 1.0, DBL_TOL));
+    assert!(equal_with_abs_tol(right[1], 1.0, DBL_TOL));
+    assert!(equal_with_abs_tol(right[2], 0.0, DBL_TOL));
+}
+
+#[test]
+fn split_third_order_rational() {
+    let p0 = Rational64::new(1, 5);
+    let p1 = Rational64::new(-3, 7);
+    let p2 = Rational64::new(4, 13);
+    let p3 = Rational64::new(-11, 17);
+
+    let c: Bernstein<Rational64, Rational64, 4> = Bernstein::new([p0, p1, p2, p3]);
+    let u = Rational64::new(1, 3);
+    let (l, r) = c.split(u);
+
+    assert_eq!(l.coef()[0], p0);
+    assert_eq!(l.coef()[3], c.eval(u));
+    assert_eq!(r.coef()[0], c.eval(u));
+    assert_eq!(r.coef()[3], p3);
+
+    // Both pieces are the same polynomial over the respective intervals.
+    for k in 0..=12 {
+        let v = Rational64::new(k, 36);
+        assert_eq!(l.eval(v), c.eval(v));
+        let v = Rational64::new(12 + 2 * k, 36);
+        assert_eq!(r.eval(v), c.eval(v));
+    }
+}
+
+#[test]
+fn restrict_third_order_rational() {
+    let p0 = Rational64::new(1, 5);
+    let p1 = Rational64::new(-3, 7);
+    let p2 = Rational64::new(4, 13);
+    let p3 = Rational64::new(-11, 17);
+
+    let c: Bernstein<Rational64, Rational64, 4> = Bernstein::new([p0, p1, p2, p3]);
+    let a = Rational64::new(1, 4);
+    let b = Rational64::new(2, 3);
+    let s = c.restrict(a, b);
+
+    assert_eq!(s.coef()[0], c.eval(a));
+    assert_eq!(s.coef()[3], c.eval(b));
+
+    for k in 0..=10 {
+        let v = a + (b - a) * Rational64::new(k, 10);
+        assert_eq!(s.eval(v), c.eval(v));
+    }
+
+    // Restriction to the piece of the split is the same as the split.
+    let (_, r) = c.split(a);
+    assert_eq!(
+        c.restrict(a, Rational64::new(1, 1)).coef().clone(),
+        r.coef().clone()
+    );
+}
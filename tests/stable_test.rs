@@ -0,0 +1,68 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+// Explicitly sized operations that are available without the `nightly`
+// feature.
+
+use num::Rational64;
+
+use bernstein::Bernstein;
+
+mod routines;
+pub use routines::*;
+
+#[test]
+fn stable_add_sub() {
+    let p: Bernstein<Rational64, Rational64, 3> = Bernstein::new([r(1, 2), r(1, 3), r(1, 4)]);
+    let q: Bernstein<Rational64, Rational64, 2> = Bernstein::new([r(1, 1), r(-1, 1)]);
+
+    // The smaller polynomial is elevated to the size of the larger one.
+    let s = p.add_into::<2, 3>(&q);
+    assert_eq!(*s.coef(), [r(3, 2), r(1, 3), r(-3, 4)]);
+    assert_eq!(*q.add_into::<3, 3>(&p).coef(), *s.coef());
+
+    let d = s.sub_into::<3, 3>(&p);
+    assert_eq!(*d.coef(), *q.elevate_to::<3>().coef());
+    assert_eq!(*q.sub_into::<3, 3>(&s).coef(), p.coef().map(|c| -c));
+}
+
+#[test]
+#[should_panic]
+fn stable_add_wrong_size() {
+    let p: Bernstein<f64, f64, 3> = Bernstein::new([1.0, 2.0, 3.0]);
+    let q: Bernstein<f64, f64, 2> = Bernstein::new([1.0, 2.0]);
+    let _ = p.add_into::<2, 4>(&q);
+}
+
+#[test]
+fn stable_mul_diff_integ() {
+    let p: Bernstein<Rational64, Rational64, 2> =
+        Bernstein::with_interval([r(1, 1), r(3, 1)], r(1, 1), r(3, 1));
+    let q: Bernstein<Rational64, Rational64, 3> =
+        Bernstein::with_interval([r(2, 1), r(0, 1), r(-1, 2)], r(1, 1), r(3, 1));
+
+    let c = p.mul_into::<3, 4>(&q);
+    for k in 0..=8 {
+        let u = r(1, 1) + r(k, 4);
+        assert_eq!(c.eval(u), p.eval(u) * q.eval(u));
+    }
+
+    // Derivative of the integral is the original polynomial.
+    let i = c.integ_into::<5>(r(7, 3));
+    assert_eq!(i.eval(r(1, 1)), r(7, 3));
+    assert_eq!(*i.diff_into::<4>().coef(), *c.coef());
+}
+
+#[test]
+#[should_panic]
+fn stable_diff_wrong_size() {
+    let p: Bernstein<f64, f64, 3> = Bernstein::new([1.0, 2.0, 3.0]);
+    let _ = p.diff_into::<3>();
+}
+
+#[test]
+#[should_panic]
+fn stable_mul_wrong_size() {
+    let p: Bernstein<f64, f64, 3> = Bernstein::new([1.0, 2.0, 3.0]);
+    let _ = p.mul_into::<3, 6>(&p);
+}
@@ -0,0 +1,158 @@
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+
+use num::complex::Complex64;
+use num::Rational64;
+
+use bernstein::{Bernstein, Cross, Dot};
+
+mod routines;
+pub use routines::*;
+
+#[test]
+fn dot_cross_complex_pointwise() {
+    let p: Bernstein<Complex64, f64, 4> = Bernstein::with_interval(
+        [
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 2.0),
+            Complex64::new(3.0, -1.0),
+            Complex64::new(4.0, 1.0),
+        ],
+        -1.0,
+        2.0,
+    );
+    let q: Bernstein<Complex64, f64, 3> = Bernstein::with_interval(
+        [
+            Complex64::new(1.0, -1.0),
+            Complex64::new(-2.0, 0.5),
+            Complex64::new(0.5, 3.0),
+        ],
+        -1.0,
+        2.0,
+    );
+    let s: Bernstein<f64, f64, 3> = Bernstein::with_interval([1.0, -2.0, 0.5], -1.0, 2.0);
+
+    let d = p.dot_into::<3, 6>(&q);
+    let c = p.cross_into::<3, 6>(&q);
+    let m = p.scale_by_into::<3, 6>(&s);
+    assert_eq!(d.interval(), (-1.0, 2.0));
+
+    for k in 0..=30 {
+        let u = -1.0 + 0.1 * k as f64;
+        let (a, b) = (p.eval(u), q.eval(u));
+        assert!(equal_with_abs_tol(d.eval(u), a.dot(&b), 1.0e-12));
+        assert!(equal_with_abs_tol(c.eval(u), a.cross(&b), 1.0e-12));
+        assert!(equal_with_abs_tol(
+            (m.eval(u) - a * s.eval(u)).norm(),
+            0.0,
+            1.0e-12
+        ));
+    }
+}
+
+#[test]
+fn scale_by_rational_exact() {
+    let p: Bernstein<Rational64, Rational64, 3> = Bernstein::new([r(1, 2), r(-3, 7), r(5, 3)]);
+    let s: Bernstein<Rational64, Rational64, 2> = Bernstein::new([r(2, 1), r(-1, 3)]);
+
+    let q = p.scale_by_into::<2, 4>(&s);
+    for k in 0..=8 {
+        let u = r(k, 8);
+        assert_eq!(q.eval(u), p.eval(u) * s.eval(u));
+    }
+}
+
+#[test]
+fn scale_by_complex() {
+    let p: Bernstein<Complex64, f64, 2> =
+        Bernstein::new([Complex64::new(1.0, 0.0), Complex64::new(0.0, 1.0)]);
+    let s: Bernstein<f64, f64, 3> = Bernstein::new([1.0, -2.0, 4.0]);
+
+    let q = p.scale_by_into::<3, 4>(&s);
+    for k in 0..=10 {
+        let u = 0.1 * k as f64;
+        let e = p.eval(u) * s.eval(u);
+        let v = q.eval(u);
+        assert!(equal_with_abs_tol(v.re, e.re, 1.0e-14));
+        assert!(equal_with_abs_tol(v.im, e.im, 1.0e-14));
+    }
+}
+
+// Both dot product and scaling of scalar polynomials are the ordinary
+// products.
+#[test]
+fn dot_scale_by_f64() {
+    const DBL_TOL: f64 = 1.0e-14;
+    let p: Bernstein<f64, f64, 3> = Bernstein::new([0.0, 1.0, -2.0]);
+    let q: Bernstein<f64, f64, 2> = Bernstein::new([1.0, 3.0]);
+
+    let d = p.dot_into::<2, 4>(&q);
+    let m = p.scale_by_into::<2, 4>(&q);
+
+    for k in 0..=10 {
+        let u = 0.1 * k as f64;
+        assert!(equal_with_abs_tol(
+            d.eval(u),
+            p.eval(u) * q.eval(u),
+            DBL_TOL
+        ));
+        assert!(equal_with_abs_tol(
+            m.eval(u),
+            p.eval(u) * q.eval(u),
+            DBL_TOL
+        ));
+    }
+}
+
+#[test]
+#[cfg(feature = "nightly")]
+fn squared_speed_of_pythagorean_hodograph() {
+    // Hodograph of a quintic Pythagorean-hodograph curve is `w^2` for the
+    // quadratic `w`, so its squared speed is `|w|^4`.
+    let w: Bernstein<Complex64, f64, 3> = Bernstein::new([
+        Complex64::new(1.0, 0.5),
+        Complex64::new(0.0, 0.0),
+        Complex64::new(-0.5, 1.0),
+    ]);
+    let h = w * w;
+    let sigma = w.dot(&w);
+    let speed2 = h.dot(&h);
+    let sigma2 = sigma * sigma;
+
+    assert_eq!(speed2.coef().len(), 9);
+    for (a, b) in speed2.coef().iter().zip(sigma2.coef()) {
+        assert!(equal_with_abs_tol(*a, *b, 1.0e-14));
+    }
+}
+
+#[test]
+#[cfg(feature = "nightly")]
+fn cross_of_hodographs() {
+    let p: Bernstein<Complex64, f64, 4> = Bernstein::new([
+        Complex64::new(0.0, 0.0),
+        Complex64::new(1.0, 1.0),
+        Complex64::new(2.0, -1.0),
+        Complex64::new(3.0, 0.0),
+    ]);
+    let d1 = p.diff();
+    let d2 = d1.diff();
+    let c = d1.cross(&d2);
+    assert_eq!(c.coef().len(), 4);
+
+    for k in 0..=10 {
+        let u = 0.1 * k as f64;
+        assert!(equal_with_abs_tol(
+            c.eval(u),
+            d1.eval(u).cross(&d2.eval(u)),
+            1.0e-12
+        ));
+    }
+}
+
+#[test]
+#[should_panic(expected = "polynomials are defined over different intervals")]
+fn dot_different_intervals() {
+    let p: Bernstein<f64, f64, 2> = Bernstein::new([1.0, 2.0]);
+    let q: Bernstein<f64, f64, 2> = Bernstein::with_interval([1.0, 2.0], 0.0, 2.0);
+    let _ = p.dot_into::<2, 3>(&q);
+}